@@ -59,3 +59,32 @@ impl AnimationManager {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn animations_request_repaint_until_done() {
+    let mut ctx = crate::CtxRef::default();
+    let id = Id::new("animation");
+    let animation_time = ctx.style().animation_time;
+    let mut needs_repaint = vec![];
+    let times = [
+        0.0,
+        1.0,
+        1.0,
+        1.0 + 0.5 * animation_time,
+        1.0 + 2.0 * animation_time,
+        1.0 + 3.0 * animation_time,
+    ];
+    for (frame, time) in times.iter().enumerate() {
+        ctx.begin_frame(crate::RawInput {
+            time: Some(*time as f64),
+            ..Default::default()
+        });
+        ctx.animate_bool(id, frame > 1);
+        needs_repaint.push(ctx.end_frame().0.needs_repaint);
+    }
+    assert!(!needs_repaint[1], "nothing to animate yet");
+    assert!(needs_repaint[2], "started animating");
+    assert!(needs_repaint[3], "still animating");
+    assert!(!needs_repaint[5], "done animating");
+}
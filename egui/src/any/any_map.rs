@@ -67,6 +67,11 @@ impl<Key: Hash + Eq> AnyMap<Key> {
         self.0.remove(key);
     }
 
+    /// Only keep the elements whose key satisfies the predicate.
+    pub fn retain(&mut self, mut keep: impl FnMut(&Key) -> bool) {
+        self.0.retain(|key, _| keep(key));
+    }
+
     pub fn remove_by_type<T: AnyMapTrait>(&mut self) {
         let key = TypeId::of::<T>();
         self.0.retain(|_, v| v.type_id() != key);
@@ -84,6 +89,10 @@ impl<Key: Hash + Eq> AnyMap<Key> {
         self.0.iter().filter(|(_, v)| v.type_id() == key).count()
     }
 
+    pub fn contains_key(&self, key: &Key) -> bool {
+        self.0.contains_key(key)
+    }
+
     pub fn count_all(&mut self) -> usize {
         self.0.len()
     }
@@ -68,6 +68,11 @@ impl<Key: Hash + Eq> AnyMap<Key> {
         self.0.remove(key);
     }
 
+    /// Only keep the elements whose key satisfies the predicate.
+    pub fn retain(&mut self, mut keep: impl FnMut(&Key) -> bool) {
+        self.0.retain(|key, _| keep(key));
+    }
+
     /// Note that this function could not remove all needed types between runs because if you upgraded the Rust version or for other reasons.
     pub fn remove_by_type<T: AnyMapTrait>(&mut self) {
         let key = TypeId::of::<T>();
@@ -86,6 +91,10 @@ impl<Key: Hash + Eq> AnyMap<Key> {
         self.0.iter().filter(|(_, v)| v.type_id() == key).count()
     }
 
+    pub fn contains_key(&self, key: &Key) -> bool {
+        self.0.contains_key(key)
+    }
+
     pub fn count_all(&mut self) -> usize {
         self.0.len()
     }
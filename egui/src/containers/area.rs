@@ -200,6 +200,7 @@ impl Area {
         } = self;
 
         let layer_id = LayerId::new(order, id);
+        ctx.memory().areas.add_to_order(layer_id);
 
         let state = ctx.memory().areas.get(id).cloned();
         let is_new = state.is_none();
@@ -294,7 +295,8 @@ impl Prepared {
             bounds.max.at_least(self.state.pos + Vec2::splat(32.0)),
         );
 
-        let shadow_radius = ctx.style().visuals.window_shadow.extrusion; // hacky
+        let shadow = ctx.style().visuals.window_shadow;
+        let shadow_radius = shadow.extrusion + shadow.offset.x.abs().max(shadow.offset.y.abs()); // hacky
         let clip_rect_margin = ctx.style().visuals.clip_rect_margin.max(shadow_radius);
 
         let clip_rect = Rect::from_min_max(self.state.pos, bounds.max)
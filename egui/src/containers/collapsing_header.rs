@@ -101,6 +101,10 @@ impl State {
     }
 }
 
+/// Paints the icon of a [`CollapsingHeader`], given how open it is (`0.0..=1.0`)
+/// and the [`Response`] of the icon (whose `rect` is where to paint it).
+pub type IconPainter = Box<dyn FnOnce(&mut Ui, f32, &Response)>;
+
 /// Paint the arrow icon that indicated if the region is open or not
 pub(crate) fn paint_icon(ui: &mut Ui, openness: f32, response: &Response) {
     let visuals = ui.style().interact(response);
@@ -143,6 +147,7 @@ pub struct CollapsingHeader {
     selectable: bool,
     selected: bool,
     show_background: bool,
+    icon: Option<IconPainter>,
 }
 
 impl CollapsingHeader {
@@ -163,6 +168,7 @@ impl CollapsingHeader {
             selectable: false,
             selected: false,
             show_background: false,
+            icon: None,
         }
     }
 
@@ -232,6 +238,31 @@ impl CollapsingHeader {
         self.show_background = show_background;
         self
     }
+
+    /// Use a custom icon instead of the default rotating triangle.
+    ///
+    /// The function is given how open the header is (`0.0` closed, `1.0` open)
+    /// and a [`Response`] whose `rect` is where the icon goes.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// fn plus_minus_icon(ui: &mut egui::Ui, openness: f32, response: &egui::Response) {
+    ///     let stroke = ui.style().interact(response).fg_stroke;
+    ///     let rect = response.rect.shrink(2.0);
+    ///     ui.painter().line_segment([rect.left_center(), rect.right_center()], stroke);
+    ///     if openness < 1.0 {
+    ///         ui.painter().line_segment([rect.center_top(), rect.center_bottom()], stroke);
+    ///     }
+    /// }
+    ///
+    /// egui::CollapsingHeader::new("Heading")
+    ///     .icon(plus_minus_icon)
+    ///     .show(ui, |ui| ui.label("Contents"));
+    /// ```
+    pub fn icon(mut self, icon: impl FnOnce(&mut Ui, f32, &Response) + 'static) -> Self {
+        self.icon = Some(Box::new(icon));
+        self
+    }
 }
 
 struct Prepared {
@@ -254,6 +285,7 @@ impl CollapsingHeader {
             selectable: _,
             selected: _,
             show_background: _,
+            icon,
         } = self;
 
         label.text_style = label
@@ -335,7 +367,11 @@ impl CollapsingHeader {
                 ..header_response.clone()
             };
             let openness = state.openness(ui.ctx(), id);
-            paint_icon(ui, openness, &icon_response);
+            if let Some(icon) = icon {
+                icon(ui, openness, &icon_response);
+            } else {
+                paint_icon(ui, openness, &icon_response);
+            }
         }
 
         ui.painter().galley_with_color(text_pos, galley, text_color);
@@ -381,15 +417,20 @@ impl CollapsingHeader {
                 .inner
             });
             ui.memory().id_data.insert(id, state);
+            let is_open = state.open;
 
             if let Some(ret_response) = ret_response {
                 CollapsingResponse {
+                    id,
+                    is_open,
                     header_response,
                     body_response: Some(ret_response.response),
                     body_returned: Some(ret_response.inner),
                 }
             } else {
                 CollapsingResponse {
+                    id,
+                    is_open,
                     header_response,
                     body_response: None,
                     body_returned: None,
@@ -402,9 +443,31 @@ impl CollapsingHeader {
 
 /// The response from showing a [`CollapsingHeader`].
 pub struct CollapsingResponse<R> {
+    /// The [`Id`] the open state is stored under.
+    pub id: Id,
+    /// Is the header open (or opening)? The body is still shown while it is closing.
+    pub is_open: bool,
     pub header_response: Response,
     /// None iff collapsed.
     pub body_response: Option<Response>,
     /// None iff collapsed.
     pub body_returned: Option<R>,
 }
+
+#[cfg(test)]
+#[test]
+fn collapsing_response_reports_open_state() {
+    let mut ctx = CtxRef::default();
+    for default_open in [false, true] {
+        ctx.begin_frame(Default::default());
+        CentralPanel::default().show(&ctx, |ui| {
+            let response = CollapsingHeader::new("Header")
+                .id_source(default_open)
+                .default_open(default_open)
+                .show(ui, |ui| ui.label("Body"));
+            assert_eq!(response.is_open, default_open);
+            assert_eq!(State::is_open(ui.ctx(), response.id), Some(default_open));
+        });
+        let _ = ctx.end_frame();
+    }
+}
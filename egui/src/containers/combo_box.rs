@@ -149,6 +149,53 @@ impl ComboBox {
         }
         response
     }
+
+    /// Pick one of the given values, e.g. the variants of an enum.
+    ///
+    /// The chosen value is written back to `current`.
+    ///
+    /// ```
+    /// # let mut ui = &mut egui::Ui::__test();
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// enum Fruit { Apple, Banana, Cherry }
+    /// let mut fruit = Fruit::Apple;
+    /// egui::ComboBox::from_label("Fruit").show_values(
+    ///     ui,
+    ///     &mut fruit,
+    ///     &[Fruit::Apple, Fruit::Banana, Fruit::Cherry],
+    ///     |fruit| format!("{:?}", fruit),
+    /// );
+    /// ```
+    pub fn show_values<T: PartialEq + Clone>(
+        self,
+        ui: &mut Ui,
+        current: &mut T,
+        values: &[T],
+        label: impl Fn(&T) -> String,
+    ) -> Response {
+        let slf = self.selected_text(label(current));
+
+        let mut changed = false;
+
+        let mut response = slf
+            .show_ui(ui, |ui| {
+                for value in values {
+                    if ui
+                        .selectable_label(value == current, label(value))
+                        .clicked()
+                    {
+                        *current = value.clone();
+                        changed = true;
+                    }
+                }
+            })
+            .response;
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
 }
 
 #[allow(clippy::needless_pass_by_value)]
@@ -209,7 +209,8 @@ impl Prepared {
             ..
         } = self;
 
-        let shape = frame.paint(outer_rect);
+        // Snap the edges to the pixel grid so that thin borders are crisp:
+        let shape = frame.paint(ui.painter().round_rect_to_pixels(outer_rect));
         ui.painter().set(where_to_put_background, shape);
         ui.allocate_rect(outer_rect, Sense::hover())
     }
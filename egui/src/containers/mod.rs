@@ -6,10 +6,12 @@ pub(crate) mod area;
 pub(crate) mod collapsing_header;
 mod combo_box;
 pub(crate) mod frame;
+mod modal;
 pub mod panel;
 pub mod popup;
 pub(crate) mod resize;
 pub(crate) mod scroll_area;
+mod split;
 pub(crate) mod window;
 
 pub use {
@@ -17,9 +19,11 @@ pub use {
     collapsing_header::*,
     combo_box::*,
     frame::Frame,
+    modal::Modal,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
     popup::*,
     resize::Resize,
     scroll_area::ScrollArea,
+    split::Split,
     window::Window,
 };
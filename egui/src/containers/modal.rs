@@ -0,0 +1,278 @@
+//! A dialog that blocks interaction with everything behind it.
+
+use std::hash::Hash;
+
+use crate::*;
+
+/// The size of the dialog last frame, used to center it on screen.
+#[derive(Clone, Copy, Debug, Default)]
+struct DialogSize(Vec2);
+
+/// A dialog window shown in the center of the screen, on top of everything else.
+///
+/// Everything behind the modal is dimmed and ignores the pointer,
+/// so the user must deal with the modal before they can continue.
+/// Just stop calling [`Self::show`] to close it.
+///
+/// Alternatively you can keep track of whether or not the modal is open in [`Memory`],
+/// using [`Memory::open_modal`] and [`Self::show_if_open`].
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// # let mut unsaved_changes = true;
+/// if unsaved_changes {
+///     egui::Modal::new("Unsaved changes").show(ctx, |ui| {
+///         ui.label("Do you want to save before quitting?");
+///         if ui.button("Save").clicked() {
+///             unsaved_changes = false;
+///         }
+///     });
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Modal {
+    title: String,
+    area: Area,
+    frame: Option<Frame>,
+    max_width: f32,
+    close_on_background_click: bool,
+}
+
+impl Modal {
+    /// The title is shown at the top of the dialog, and is also used as the id source.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(title: impl ToString) -> Self {
+        let title = title.to_string();
+        let area = Area::new(&title).order(Order::Foreground);
+        Self {
+            title,
+            area,
+            frame: None,
+            max_width: 320.0,
+            close_on_background_click: false,
+        }
+    }
+
+    /// The id of the modal, for use with [`Memory::open_modal`].
+    pub fn id(&self) -> Id {
+        self.area.id
+    }
+
+    /// Use a different id than the title.
+    pub fn id_source(mut self, id_source: impl Hash) -> Self {
+        self.area = self.area.id(Id::new(id_source));
+        self
+    }
+
+    /// Change the background color, margins, etc of the dialog.
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Text in the dialog will wrap at this width. Default: `320.0`.
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Close the modal with [`Memory::close_modal`] when the user clicks the dimmed background.
+    /// Default: `false`.
+    pub fn close_on_background_click(mut self, close_on_background_click: bool) -> Self {
+        self.close_on_background_click = close_on_background_click;
+        self
+    }
+
+    /// Only show the modal if it has been opened with [`Memory::open_modal`].
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// # let ui = &mut egui::Ui::__test();
+    /// let modal = egui::Modal::new("Are you sure?").close_on_background_click(true);
+    /// if ui.button("Delete").clicked() {
+    ///     ui.memory().open_modal(modal.id());
+    /// }
+    /// modal.show_if_open(ctx, |ui| {
+    ///     if ui.button("Yes").clicked() {
+    ///         /* delete it */
+    ///         ui.memory().close_modal();
+    ///     }
+    /// });
+    /// ```
+    pub fn show_if_open<R>(
+        self,
+        ctx: &CtxRef,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        if ctx.memory().is_modal_open(self.id()) {
+            Some(self.show(ctx, add_contents))
+        } else {
+            None
+        }
+    }
+
+    /// The returned [`Response`] is for the dimmed background behind the dialog,
+    /// so you can use `response.clicked()` to close the modal when the user clicks outside it.
+    pub fn show<R>(
+        self,
+        ctx: &CtxRef,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            title,
+            area,
+            frame,
+            max_width,
+            close_on_background_click,
+        } = self;
+
+        ctx.memory().set_modal(area.layer());
+
+        let screen_rect = ctx.input().screen_rect();
+        let frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
+        let size_id = area.id.with("dialog_size");
+        let dialog_id = area.id.with("dialog");
+
+        let inner_response = area.fixed_pos(screen_rect.min).show(ctx, |ui| {
+            // Cover the whole screen, so that the pointer never reaches anything behind us:
+            ui.expand_to_include_rect(screen_rect);
+            ui.painter()
+                .rect_filled(screen_rect, 0.0, Color32::from_black_alpha(128));
+
+            let old_size = ctx
+                .memory()
+                .id_data_temp
+                .get::<DialogSize>(&size_id)
+                .map_or(Vec2::ZERO, |size| size.0);
+            let dialog_rect = Align2::CENTER_CENTER.align_size_within_rect(old_size, screen_rect);
+            let max_rect = Rect::from_min_size(
+                dialog_rect.min,
+                vec2(max_width, screen_rect.bottom() - dialog_rect.top()),
+            );
+
+            let mut dialog_ui = ui.child_ui(max_rect, Layout::top_down(Align::Min));
+            let InnerResponse { inner, response } = frame.show(&mut dialog_ui, |ui| {
+                ui.heading(title);
+                ui.separator();
+                add_contents(ui)
+            });
+
+            // Claim clicks on the dialog itself, so they don't count as clicks on the background:
+            ui.interact(response.rect, dialog_id, Sense::click());
+
+            let new_size = response.rect.size();
+            if new_size != old_size {
+                ctx.memory()
+                    .id_data_temp
+                    .insert(size_id, DialogSize(new_size));
+                ctx.request_repaint(); // center it properly
+            }
+
+            inner
+        });
+
+        if close_on_background_click && inner_response.response.clicked() {
+            ctx.memory().close_modal();
+        }
+
+        inner_response
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn modal_blocks_clicks_behind_it() {
+    fn button_clicked(show_modal: bool) -> bool {
+        let mut ctx = CtxRef::default();
+        let button_pos = pos2(20.0, 20.0);
+        let events = vec![
+            vec![],
+            vec![Event::PointerMoved(button_pos)],
+            vec![Event::PointerButton {
+                pos: button_pos,
+                button: PointerButton::Primary,
+                pressed: true,
+                modifiers: Default::default(),
+            }],
+            vec![Event::PointerButton {
+                pos: button_pos,
+                button: PointerButton::Primary,
+                pressed: false,
+                modifiers: Default::default(),
+            }],
+        ];
+
+        let mut clicked = false;
+        for events in events {
+            ctx.begin_frame(RawInput {
+                events,
+                ..Default::default()
+            });
+            CentralPanel::default().show(&ctx, |ui| {
+                clicked |= ui.button("Behind").clicked();
+            });
+            if show_modal {
+                Modal::new("Modal").show(&ctx, |ui| ui.label("In front"));
+            }
+            let _ = ctx.end_frame();
+        }
+        clicked
+    }
+
+    assert!(button_clicked(false));
+    assert!(!button_clicked(true));
+}
+
+#[cfg(test)]
+#[test]
+fn modal_closes_on_background_click() {
+    /// Returns whether the modal is still open after the frame, and where the dialog is.
+    fn run_frame(ctx: &mut CtxRef, events: Vec<Event>) -> (bool, Rect) {
+        let modal = Modal::new("Modal").close_on_background_click(true);
+        let modal_id = modal.id();
+        ctx.begin_frame(RawInput {
+            events,
+            ..Default::default()
+        });
+        let dialog_rect = modal
+            .show_if_open(ctx, |ui| ui.label("In front").rect)
+            .map_or(Rect::NOTHING, |response| response.inner);
+        let _ = ctx.end_frame();
+        (ctx.memory().is_modal_open(modal_id), dialog_rect)
+    }
+
+    fn click(pos: Pos2) -> Vec<Event> {
+        vec![
+            Event::PointerButton {
+                pos,
+                button: PointerButton::Primary,
+                pressed: true,
+                modifiers: Default::default(),
+            },
+            Event::PointerButton {
+                pos,
+                button: PointerButton::Primary,
+                pressed: false,
+                modifiers: Default::default(),
+            },
+        ]
+    }
+
+    let mut ctx = CtxRef::default();
+    assert!(!run_frame(&mut ctx, vec![]).0, "closed until opened");
+
+    ctx.memory().open_modal(Modal::new("Modal").id());
+    run_frame(&mut ctx, vec![]);
+    let (is_open, label_rect) = run_frame(&mut ctx, vec![]);
+    assert!(is_open);
+
+    assert!(
+        run_frame(&mut ctx, click(label_rect.center())).0,
+        "clicking the dialog keeps it open"
+    );
+    assert!(!run_frame(&mut ctx, click(pos2(5.0, 5.0))).0);
+}
@@ -12,6 +12,21 @@
 //! Always add any [`CentralPanel`] last.
 //!
 //! Add your [`Window`]:s after any top-level panels.
+//!
+//! Panels can also split up a part of a [`Ui`] using `show_inside`:
+//!
+//! ```
+//! # let ui = &mut egui::Ui::__test();
+//! egui::TopBottomPanel::top("toolbar").show_inside(ui, |ui| {
+//!     ui.label("Toolbar");
+//! });
+//! egui::SidePanel::left("outline").show_inside(ui, |ui| {
+//!     ui.label("Outline");
+//! });
+//! egui::CentralPanel::default().show_inside(ui, |ui| {
+//!     ui.label("Takes up whatever space is left");
+//! });
+//! ```
 
 use std::ops::RangeInclusive;
 
@@ -681,3 +696,75 @@ fn clamp_to_range(x: f32, range: RangeInclusive<f32>) -> f32 {
         range.start().max(*range.end()),
     )
 }
+
+#[cfg(test)]
+#[test]
+fn resize_side_panel() {
+    /// Drag the right edge of a left panel to `x`, and return where the remaining space starts.
+    fn drag_edge_to(x: f32) -> f32 {
+        let mut ctx = CtxRef::default();
+        let edge = pos2(200.0, 100.0);
+        let events = vec![
+            vec![Event::PointerMoved(edge)],
+            vec![Event::PointerButton {
+                pos: edge,
+                button: PointerButton::Primary,
+                pressed: true,
+                modifiers: Default::default(),
+            }],
+            vec![Event::PointerMoved(pos2(x, edge.y))],
+        ];
+        for events in events {
+            ctx.begin_frame(RawInput {
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+                events,
+                ..Default::default()
+            });
+            SidePanel::left("left")
+                .default_width(200.0)
+                .width_range(100.0..=400.0)
+                .show(&ctx, |ui| {
+                    ui.allocate_space(ui.available_size()); // fill the panel
+                });
+            let _ = ctx.end_frame();
+        }
+        ctx.available_rect().left()
+    }
+
+    assert_eq!(drag_edge_to(200.0), 200.0);
+    assert_eq!(drag_edge_to(300.0), 300.0);
+    assert_eq!(drag_edge_to(700.0), 400.0, "clamped to the width range");
+}
+
+#[cfg(test)]
+#[test]
+fn central_panel_gets_what_is_left() {
+    let mut ctx = CtxRef::default();
+    let screen_rect = Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0));
+    ctx.begin_frame(RawInput {
+        screen_rect: Some(screen_rect),
+        ..Default::default()
+    });
+    let fill = |ui: &mut Ui| {
+        ui.allocate_space(ui.available_size());
+    };
+    TopBottomPanel::top("menu_bar")
+        .resizable(false)
+        .default_height(30.0)
+        .show(&ctx, fill);
+    TopBottomPanel::bottom("status_bar")
+        .resizable(false)
+        .default_height(20.0)
+        .show(&ctx, fill);
+    SidePanel::left("outline")
+        .resizable(false)
+        .default_width(150.0)
+        .show(&ctx, fill);
+    let central_rect = CentralPanel::default().show(&ctx, fill).response.rect;
+    let _ = ctx.end_frame();
+
+    assert_eq!(
+        central_rect,
+        Rect::from_min_max(pos2(150.0, 30.0), pos2(800.0, 580.0))
+    );
+}
@@ -277,7 +277,7 @@ pub fn popup_below_widget<R>(
             })
             .inner;
 
-        if ui.input().key_pressed(Key::Escape) || widget_response.clicked_elsewhere() {
+        if widget_response.clicked_elsewhere() {
             ui.memory().close_popup();
         }
         Some(inner)
@@ -285,3 +285,68 @@ pub fn popup_below_widget<R>(
         None
     }
 }
+
+#[cfg(test)]
+#[test]
+fn nested_tooltip_is_painted_above_its_parent() {
+    let mut ctx = CtxRef::default();
+    let mut texts = vec![];
+    for _ in 0..3 {
+        ctx.begin_frame(Default::default());
+        show_tooltip_at(&ctx, Id::new("outer"), Some(pos2(10.0, 10.0)), |ui| {
+            ui.label("outer");
+            show_tooltip_at(ui.ctx(), Id::new("inner"), Some(pos2(20.0, 20.0)), |ui| {
+                ui.label("inner");
+            });
+        });
+        let (_, shapes) = ctx.end_frame();
+        texts = shapes
+            .into_iter()
+            .filter_map(|clipped| match clipped.1 {
+                Shape::Text(text) => Some(text.galley.text().to_owned()),
+                _ => None,
+            })
+            .collect();
+    }
+    assert_eq!(texts, ["outer", "inner"]);
+}
+
+#[cfg(test)]
+#[test]
+fn tooltip_in_popup_is_painted_above_everything() {
+    let mut ctx = CtxRef::default();
+    let popup_id = Id::new("popup");
+    let mut texts = vec![];
+    for _ in 0..3 {
+        ctx.begin_frame(Default::default());
+        ctx.memory().open_popup(popup_id);
+        CentralPanel::default().show(&ctx, |ui| {
+            let response = ui.button("button");
+            popup_below_widget(ui, popup_id, &response, |ui| {
+                show_tooltip_at(ui.ctx(), Id::new("tooltip"), Some(pos2(20.0, 20.0)), |ui| {
+                    ui.label("tooltip");
+                });
+                ui.label("popup");
+            });
+        });
+        Window::new("window").show(&ctx, |ui| ui.label("window contents"));
+        let (_, shapes) = ctx.end_frame();
+        texts = shapes
+            .into_iter()
+            .filter_map(|clipped| match clipped.1 {
+                Shape::Text(text) => Some(text.galley.text().to_owned()),
+                _ => None,
+            })
+            .collect();
+    }
+    assert_eq!(
+        texts,
+        [
+            "button",
+            "window contents",
+            "window", // the title bar is painted after the contents
+            "popup",
+            "tooltip",
+        ]
+    );
+}
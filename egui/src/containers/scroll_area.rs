@@ -190,6 +190,10 @@ impl ScrollArea {
     }
 }
 
+/// The height of the header of [`ScrollArea::show_with_header`] last frame.
+#[derive(Clone, Copy, Debug, Default)]
+struct HeaderHeight(f32);
+
 struct Prepared {
     id: Id,
     state: State,
@@ -360,6 +364,68 @@ impl ScrollArea {
         self.show_viewport_dyn(ui, Box::new(add_contents))
     }
 
+    /// Show a header that stays at the top while the body scrolls beneath it,
+    /// e.g. the column names of a table.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// egui::ScrollArea::vertical().show_with_header(
+    ///     ui,
+    ///     |ui| {
+    ///         ui.heading("Name");
+    ///         ui.separator();
+    ///     },
+    ///     |ui| {
+    ///         for i in 0..100 {
+    ///             ui.label(format!("Item {}", i));
+    ///         }
+    ///     },
+    /// );
+    /// ```
+    pub fn show_with_header<R>(
+        self,
+        ui: &mut Ui,
+        add_header: impl FnOnce(&mut Ui),
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        let mut prepared = self.begin(ui);
+        let header_height_id = prepared.id.with("header_height");
+        let header_top = prepared.inner_rect.top();
+        let content_ui = &mut prepared.content_ui;
+
+        // We don't know the height of the header until we have laid it out, so use last frame's:
+        let header_height = content_ui
+            .memory()
+            .id_data_temp
+            .get::<HeaderHeight>(&header_height_id)
+            .map_or(0.0, |height| height.0);
+
+        // Leave room for the header, and make sure the body is never painted or hovered under it:
+        let clip_rect = content_ui.clip_rect();
+        let mut body_clip_rect = clip_rect;
+        body_clip_rect.min.y = body_clip_rect.min.y.max(header_top + header_height);
+        content_ui.add_space(header_height);
+        content_ui.set_clip_rect(body_clip_rect);
+        let ret = add_body(content_ui);
+        content_ui.set_clip_rect(clip_rect);
+
+        let header_rect =
+            Rect::from_x_y_ranges(content_ui.max_rect().x_range(), header_top..=f32::INFINITY);
+        let mut header_ui = content_ui.child_ui(header_rect, *content_ui.layout());
+        add_header(&mut header_ui);
+        let new_header_height = header_ui.min_rect().height() + header_ui.spacing().item_spacing.y;
+        if new_header_height != header_height {
+            content_ui
+                .memory()
+                .id_data_temp
+                .insert(header_height_id, HeaderHeight(new_header_height));
+            content_ui.ctx().request_repaint();
+        }
+
+        prepared.end(ui);
+        ret
+    }
+
     fn show_viewport_dyn<'c, R>(
         self,
         ui: &mut Ui,
@@ -665,3 +731,139 @@ impl Prepared {
 fn max_scroll_bar_width_with_margin(ui: &Ui) -> f32 {
     ui.spacing().item_spacing.x + ui.spacing().scroll_bar_width
 }
+
+#[cfg(test)]
+#[test]
+fn nested_scroll_areas_clip_hovering() {
+    /// Hover a button inside an inner scroll area, 150 points down into an outer scroll area.
+    fn button_hovered(outer_height: f32) -> bool {
+        let mut ctx = CtxRef::default();
+        let mut button_rect = Rect::NOTHING;
+        let mut hovered = false;
+        for frame in 0..3 {
+            let events = if frame == 0 {
+                vec![]
+            } else {
+                vec![Event::PointerMoved(button_rect.center())]
+            };
+            ctx.begin_frame(RawInput {
+                events,
+                ..Default::default()
+            });
+            CentralPanel::default().show(&ctx, |ui| {
+                ScrollArea::vertical()
+                    .id_source("outer")
+                    .max_height(outer_height)
+                    .show(ui, |ui| {
+                        ui.add_space(50.0);
+                        ScrollArea::vertical()
+                            .id_source("inner")
+                            .max_height(200.0)
+                            .show(ui, |ui| {
+                                ui.add_space(100.0);
+                                let response = ui.button("Button");
+                                button_rect = response.rect;
+                                hovered = response.hovered();
+                            });
+                    });
+            });
+            let _ = ctx.end_frame();
+        }
+        hovered
+    }
+
+    assert!(button_hovered(400.0));
+    assert!(
+        !button_hovered(100.0),
+        "Clipped away by the outer scroll area"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn sticky_header_stays_on_top() {
+    let mut ctx = CtxRef::default();
+    let mut scroll_area_top = 0.0;
+    let mut header_top = 0.0;
+    let mut first_row_top = 0.0;
+    for frame in 0..5 {
+        ctx.begin_frame(RawInput {
+            events: vec![Event::PointerMoved(pos2(50.0, 50.0))],
+            scroll_delta: if frame == 3 {
+                vec2(0.0, -100.0)
+            } else {
+                Vec2::ZERO
+            },
+            ..Default::default()
+        });
+        CentralPanel::default().show(&ctx, |ui| {
+            scroll_area_top = ui.cursor().top();
+            ScrollArea::vertical().max_height(200.0).show_with_header(
+                ui,
+                |ui| header_top = ui.label("Header").rect.top(),
+                |ui| {
+                    for i in 0..100 {
+                        let rect = ui.label(format!("Row {}", i)).rect;
+                        if i == 0 {
+                            first_row_top = rect.top();
+                        }
+                    }
+                },
+            );
+        });
+        let _ = ctx.end_frame();
+
+        if frame == 2 {
+            assert!(
+                first_row_top > header_top,
+                "the body starts below the header"
+            );
+        }
+    }
+
+    assert!(
+        first_row_top < scroll_area_top,
+        "the body has been scrolled"
+    );
+    assert_eq!(header_top, scroll_area_top, "the header has not");
+}
+
+#[cfg(test)]
+#[test]
+fn show_rows_only_adds_visible_rows() {
+    let mut ctx = CtxRef::default();
+    let mut built_rows = vec![];
+    for frame in 0..4 {
+        ctx.begin_frame(RawInput {
+            events: vec![Event::PointerMoved(pos2(50.0, 50.0))],
+            scroll_delta: if frame == 2 {
+                vec2(0.0, -10_000.0)
+            } else {
+                Vec2::ZERO
+            },
+            ..Default::default()
+        });
+        built_rows.clear();
+        CentralPanel::default().show(&ctx, |ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            ScrollArea::vertical()
+                .max_height(200.0)
+                .show_rows(ui, 20.0, 1_000, |ui, row_range| {
+                    for row in row_range {
+                        built_rows.push(row);
+                        ui.allocate_exact_size(vec2(100.0, 20.0), Sense::hover());
+                    }
+                });
+        });
+        let _ = ctx.end_frame();
+
+        if frame == 0 {
+            assert_eq!(built_rows.first(), Some(&0));
+            assert!(built_rows.len() <= 12, "{:?}", built_rows);
+        }
+    }
+
+    // We can only scroll this far down if the full height of all rows has been allocated:
+    assert_eq!(built_rows.first(), Some(&500));
+    assert!(built_rows.len() <= 12, "{:?}", built_rows);
+}
@@ -0,0 +1,216 @@
+//! Two panes next to each other, with a draggable divider in between.
+
+use std::hash::Hash;
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+struct SplitState {
+    /// Where the divider is, from `0.0` (left/top) to `1.0` (right/bottom).
+    fraction: f32,
+}
+
+/// Splits the available space of a [`Ui`] into two panes with a draggable divider in between.
+///
+/// The position of the divider is remembered (as a fraction of the available space),
+/// and resizing the parent [`Ui`] resizes both panes.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// egui::Split::horizontal("editor_split")
+///     .default_fraction(0.3)
+///     .show(
+///         ui,
+///         |ui| ui.label("File tree"),
+///         |ui| ui.label("Editor"),
+///     );
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Split {
+    id_source: Id,
+    horizontal: bool,
+    default_fraction: f32,
+    min_size: f32,
+}
+
+impl Split {
+    /// Left and right panes, with a vertical divider.
+    pub fn horizontal(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            horizontal: true,
+            default_fraction: 0.5,
+            min_size: 32.0,
+        }
+    }
+
+    /// Top and bottom panes, with a horizontal divider.
+    pub fn vertical(id_source: impl Hash) -> Self {
+        Self {
+            horizontal: false,
+            ..Self::horizontal(id_source)
+        }
+    }
+
+    /// Where to put the divider before the user has moved it,
+    /// from `0.0` (left/top) to `1.0` (right/bottom). Default: `0.5`.
+    pub fn default_fraction(mut self, default_fraction: f32) -> Self {
+        self.default_fraction = default_fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The divider can't be dragged to make either pane smaller than this. Default: `32.0`.
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+}
+
+impl Split {
+    /// Returns what the two closures returned.
+    pub fn show<R1, R2>(
+        self,
+        ui: &mut Ui,
+        add_first: impl FnOnce(&mut Ui) -> R1,
+        add_second: impl FnOnce(&mut Ui) -> R2,
+    ) -> (R1, R2) {
+        let Self {
+            id_source,
+            horizontal,
+            default_fraction,
+            min_size,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let d = if horizontal { 0 } else { 1 };
+        let rect = ui.available_rect_before_wrap();
+        let divider_thickness = ui.spacing().item_spacing[d];
+        let usable_size = (rect.size()[d] - divider_thickness).at_least(0.0);
+
+        let mut fraction = ui
+            .memory()
+            .id_data
+            .get::<SplitState>(&id)
+            .map_or(default_fraction, |state| state.fraction);
+
+        let divider_rect = |fraction: f32| {
+            let mut divider_rect = rect;
+            divider_rect.min[d] = rect.min[d] + fraction * usable_size;
+            divider_rect.max[d] = divider_rect.min[d] + divider_thickness;
+            divider_rect
+        };
+
+        let mut grab_radius = Vec2::ZERO;
+        grab_radius[d] = ui.style().interaction.resize_grab_radius_side;
+        let divider_response = ui.interact(
+            divider_rect(fraction).expand2(grab_radius),
+            id.with("divider"),
+            Sense::drag(),
+        );
+        if divider_response.dragged() && usable_size > 0.0 {
+            if let Some(pointer_pos) = divider_response.interact_pointer_pos() {
+                fraction = (pointer_pos[d] - rect.min[d] - 0.5 * divider_thickness) / usable_size;
+            }
+        }
+        if usable_size > 0.0 {
+            let min_fraction = (min_size / usable_size).min(0.5);
+            fraction = fraction.clamp(min_fraction, 1.0 - min_fraction);
+        }
+        ui.memory().id_data.insert(id, SplitState { fraction });
+
+        if divider_response.hovered() || divider_response.dragged() {
+            ui.output().cursor_icon = if horizontal {
+                CursorIcon::ResizeHorizontal
+            } else {
+                CursorIcon::ResizeVertical
+            };
+        }
+
+        let divider_rect = divider_rect(fraction);
+        let mut first_rect = rect;
+        first_rect.max[d] = divider_rect.min[d];
+        let mut second_rect = rect;
+        second_rect.min[d] = divider_rect.max[d];
+
+        let first = add_first(&mut pane_ui(ui, first_rect, "first"));
+        let second = add_second(&mut pane_ui(ui, second_rect, "second"));
+
+        let stroke = if divider_response.dragged() {
+            ui.visuals().widgets.active.bg_stroke
+        } else if divider_response.hovered() {
+            ui.visuals().widgets.hovered.bg_stroke
+        } else {
+            ui.visuals().widgets.noninteractive.bg_stroke
+        };
+        let center = divider_rect.center();
+        let line = if horizontal {
+            [
+                pos2(center.x, divider_rect.top()),
+                pos2(center.x, divider_rect.bottom()),
+            ]
+        } else {
+            [
+                pos2(divider_rect.left(), center.y),
+                pos2(divider_rect.right(), center.y),
+            ]
+        };
+        ui.painter().line_segment(line, stroke);
+
+        ui.allocate_rect(rect, Sense::hover());
+
+        (first, second)
+    }
+}
+
+/// A child [`Ui`] for one of the panes, clipped to it.
+fn pane_ui(ui: &mut Ui, pane_rect: Rect, id_source: &str) -> Ui {
+    let mut pane_ui =
+        ui.child_ui_with_id_source(pane_rect, Layout::top_down(Align::Min), id_source);
+    pane_ui.set_clip_rect(pane_rect.intersect(ui.clip_rect()));
+    pane_ui
+}
+
+#[cfg(test)]
+#[test]
+fn dragging_split_divider() {
+    fn drag_divider_to(x: f32) -> f32 {
+        let mut ctx = CtxRef::default();
+        let mut divider_x = 0.0;
+        let mut fraction = 0.0;
+        for frame in 0..4 {
+            let events = match frame {
+                0 => vec![],
+                1 => vec![Event::PointerMoved(pos2(divider_x, 50.0))],
+                2 => vec![Event::PointerButton {
+                    pos: pos2(divider_x, 50.0),
+                    button: PointerButton::Primary,
+                    pressed: true,
+                    modifiers: Default::default(),
+                }],
+                _ => vec![Event::PointerMoved(pos2(x, 50.0))],
+            };
+            ctx.begin_frame(RawInput {
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 300.0))),
+                events,
+                ..Default::default()
+            });
+            CentralPanel::default().show(&ctx, |ui| {
+                let id = ui.make_persistent_id(Id::new("split"));
+                Split::horizontal("split").min_size(50.0).show(
+                    ui,
+                    |ui| divider_x = ui.max_rect().right() + 1.0,
+                    |_| {},
+                );
+                fraction = ui.memory().id_data.get::<SplitState>(&id).unwrap().fraction;
+            });
+            let _ = ctx.end_frame();
+        }
+        fraction
+    }
+
+    assert!((drag_divider_to(100.0) - 0.25).abs() < 0.05);
+    let max_fraction = drag_divider_to(10_000.0);
+    assert!(max_fraction < 0.9, "{} should be clamped", max_fraction);
+    assert!(drag_divider_to(-100.0) > 0.1);
+}
@@ -30,6 +30,7 @@ pub struct Window<'open> {
     resize: Resize,
     scroll: ScrollArea,
     collapsible: bool,
+    default_open: bool,
     with_title_bar: bool,
 }
 
@@ -53,6 +54,7 @@ impl<'open> Window<'open> {
                 .default_size([340.0, 420.0]), // Default inner size of a window
             scroll: ScrollArea::neither(),
             collapsible: true,
+            default_open: true,
             with_title_bar: true,
         }
     }
@@ -68,6 +70,18 @@ impl<'open> Window<'open> {
     /// * If `*open == false`, the window will not be visible.
     /// * If `*open == true`, the window will have a close button.
     /// * If the close button is pressed, `*open` will be set to `false`.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// let mut is_open = true;
+    /// egui::Window::new("Closable")
+    ///     .open(&mut is_open)
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Close me with the button in the title bar");
+    ///     });
+    /// ```
     pub fn open(mut self, open: &'open mut bool) -> Self {
         self.open = Some(open);
         self
@@ -191,6 +205,14 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Should the window start out expanded (`true`, default) or collapsed to just the title bar?
+    ///
+    /// The contents of a collapsed window are not shown, so `add_contents` will not be called.
+    pub fn default_open(mut self, default_open: bool) -> Self {
+        self.default_open = default_open;
+        self
+    }
+
     /// Show title bar on top of the window?
     /// If `false`, the window will not be collapsible nor have a close-button.
     pub fn title_bar(mut self, title_bar: bool) -> Self {
@@ -262,6 +284,7 @@ impl<'open> Window<'open> {
             resize,
             scroll,
             collapsible,
+            default_open,
             with_title_bar,
         } = self;
 
@@ -280,7 +303,7 @@ impl<'open> Window<'open> {
         let collapsing_id = area_id.with("collapsing");
 
         let is_collapsed = with_title_bar
-            && !collapsing_header::State::is_open(ctx, collapsing_id).unwrap_or_default();
+            && !collapsing_header::State::is_open(ctx, collapsing_id).unwrap_or(default_open);
         let possible = PossibleInteractions::new(&area, &resize, is_collapsed);
 
         let area = area.movable(false); // We move it manually, or the area will move the window when we want to resize it
@@ -331,11 +354,10 @@ impl<'open> Window<'open> {
             let frame_stroke = frame.stroke;
             let mut frame = frame.begin(&mut area_content_ui);
 
-            let default_expanded = true;
             let mut collapsing = collapsing_header::State::from_memory_with_default_open(
                 ctx,
                 collapsing_id,
-                default_expanded,
+                default_open,
             );
             let show_close_button = open.is_some();
             let title_bar = if with_title_bar {
@@ -905,3 +927,52 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+#[cfg(test)]
+#[test]
+fn window_captures_pointer() {
+    fn button_clicked(window_pos: Pos2) -> bool {
+        let mut ctx = CtxRef::default();
+        let button_pos = pos2(20.0, 20.0);
+        let events = vec![
+            vec![],
+            vec![Event::PointerMoved(button_pos)],
+            vec![Event::PointerButton {
+                pos: button_pos,
+                button: PointerButton::Primary,
+                pressed: true,
+                modifiers: Default::default(),
+            }],
+            vec![Event::PointerButton {
+                pos: button_pos,
+                button: PointerButton::Primary,
+                pressed: false,
+                modifiers: Default::default(),
+            }],
+        ];
+
+        let mut clicked = false;
+        let mut hovered = false;
+        for events in events {
+            ctx.begin_frame(RawInput {
+                events,
+                ..Default::default()
+            });
+            CentralPanel::default().show(&ctx, |ui| {
+                let response = ui.button("Behind");
+                clicked |= response.clicked();
+                hovered |= response.hovered();
+            });
+            Window::new("On top")
+                .fixed_pos(window_pos)
+                .fixed_size(vec2(100.0, 100.0))
+                .show(&ctx, |ui| ui.label("Covering the button"));
+            let _ = ctx.end_frame();
+        }
+        assert_eq!(clicked, hovered);
+        clicked
+    }
+
+    assert!(button_clicked(pos2(300.0, 300.0)));
+    assert!(!button_clicked(pos2(0.0, 0.0)));
+}
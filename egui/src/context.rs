@@ -51,6 +51,7 @@ use epaint::{stats::*, text::Fonts, *};
 /// }
 /// ```
 ///
+/// Cloning a `CtxRef` is cheap (it is just an `Arc`), so pass it around freely.
 #[derive(Clone)]
 pub struct CtxRef(std::sync::Arc<Context>);
 
@@ -119,6 +120,13 @@ impl CtxRef {
                 return;
             }
 
+            {
+                let id_clashes = &mut self.frame_state().id_clashes;
+                if !id_clashes.contains(&id) {
+                    id_clashes.push(id);
+                }
+            }
+
             let show_error = |pos: Pos2, text: String| {
                 let painter = self.debug_painter();
                 let rect = painter.error(pos, text);
@@ -127,7 +135,8 @@ impl CtxRef {
                         painter.error(
                             rect.left_bottom() + vec2(2.0, 4.0),
                             "ID clashes happens when things like Windows or CollapsingHeaders share names,\n\
-                             or when things like ScrollAreas and Resize areas aren't given unique id_source:s.",
+                             or when things like ScrollAreas and Resize areas aren't given unique id_source:s.\n\
+                             Wrap one of them in `ui.push_id(…)` to make its ID unique.",
                         );
                     }
                 }
@@ -167,6 +176,12 @@ impl CtxRef {
                 .at_most(Vec2::splat(5.0)),
         ); // make it easier to click
         let hovered = self.rect_contains_pointer(layer_id, clip_rect.intersect(interact_rect));
+
+        if sense.interactive() && self.style().debug.show_widgets {
+            self.debug_painter()
+                .rect_stroke(interact_rect, 0.0, (1.0, Color32::RED));
+        }
+
         self.interact_with_hovered(layer_id, id, rect, sense, enabled, hovered)
     }
 
@@ -182,6 +197,12 @@ impl CtxRef {
     ) -> Response {
         let hovered = hovered && enabled; // can't even hover disabled widgets
 
+        if self.style().debug.record_ids {
+            self.frame_state()
+                .recorded_ids
+                .push((layer_id, id, rect, sense));
+        }
+
         let mut response = Response {
             ctx: self.clone(),
             layer_id,
@@ -194,6 +215,7 @@ impl CtxRef {
             double_clicked: Default::default(),
             dragged: false,
             drag_released: false,
+            pressed: false,
             is_pointer_button_down_on: false,
             interact_pointer_pos: None,
             changed: false, // must be set by the widget itself
@@ -241,6 +263,7 @@ impl CtxRef {
                             if sense.click && memory.interaction.click_id.is_none() {
                                 // potential start of a click
                                 memory.interaction.click_id = Some(id);
+                                response.pressed = true;
                                 response.is_pointer_button_down_on = true;
                             }
 
@@ -257,6 +280,7 @@ impl CtxRef {
                                 memory.interaction.drag_id = Some(id);
                                 memory.interaction.drag_is_window = false;
                                 memory.window_interaction = None; // HACK: stop moving windows (if any)
+                                response.pressed = true;
                                 response.is_pointer_button_down_on = true;
                                 response.dragged = true;
                             }
@@ -283,7 +307,11 @@ impl CtxRef {
             response.interact_pointer_pos = self.input().pointer.interact_pos();
         }
 
-        if self.input.pointer.any_down() {
+        // While another widget is being dragged it captures the pointer,
+        // including the frame the drag is released on:
+        let dragging_other =
+            matches!(self.memory().interaction.drag_id, Some(drag_id) if drag_id != id);
+        if self.input.pointer.any_down() || dragging_other {
             response.hovered &= response.is_pointer_button_down_on; // we don't hover widgets while interacting with *other* widgets
         }
 
@@ -345,6 +373,9 @@ pub struct Context {
     repaint_requests: AtomicU32,
 }
 
+/// A shallow clone: memory, graphics, output etc are shared with the original (they are behind `Arc`s).
+/// Only the [`InputState`] is copied, which is what [`CtxRef::begin_frame`] needs
+/// to swap in the new input without locking.
 impl Clone for Context {
     fn clone(&self) -> Self {
         Context {
@@ -391,6 +422,16 @@ impl Context {
     /// Call this if there is need to repaint the UI, i.e. if you are showing an animation.
     /// If this is called at least once in a frame, then there will be another frame right after this.
     /// Call as many times as you wish, only one repaint will be issued.
+    ///
+    /// The backend is told about this with [`Output::needs_repaint`]:
+    ///
+    /// ```
+    /// let mut ctx = egui::CtxRef::default();
+    /// ctx.begin_frame(Default::default());
+    /// ctx.request_repaint(); // e.g. because an animation is running
+    /// let (output, _shapes) = ctx.end_frame();
+    /// assert!(output.needs_repaint); // so the backend should paint another frame right away
+    /// ```
     pub fn request_repaint(&self) {
         // request two frames of repaint, just to cover some corner cases (frame delays):
         let times_to_repaint = 2;
@@ -402,6 +443,62 @@ impl Context {
         &self.input
     }
 
+    /// Was the given keyboard shortcut pressed this frame?
+    ///
+    /// Only the first call for a given shortcut each frame returns `true`,
+    /// so that e.g. a menu item and a toolbar button don't both act on the same press.
+    ///
+    /// Shortcuts without `Ctrl`, `Alt` or `⌘` are ignored while a widget has keyboard focus
+    /// (see [`Self::wants_keyboard_input`]), so typing into a [`TextEdit`] doesn't trigger them.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # let mut raw_input = egui::RawInput::default();
+    /// # raw_input.events.push(egui::Event::Key {
+    /// #     key: egui::Key::S,
+    /// #     pressed: true,
+    /// #     modifiers: egui::Modifiers { ctrl: true, command: true, ..Default::default() },
+    /// # });
+    /// # ctx.begin_frame(raw_input);
+    /// let mut saved = false;
+    /// let save_shortcut = egui::Shortcut::command(egui::Key::S);
+    /// if ctx.consume_shortcut(&save_shortcut) {
+    ///     saved = true;
+    /// }
+    /// assert!(saved);
+    /// assert!(!ctx.consume_shortcut(&save_shortcut), "already consumed");
+    /// ```
+    pub fn consume_shortcut(&self, shortcut: &Shortcut) -> bool {
+        if !self.input.shortcut_pressed(shortcut) {
+            return false;
+        }
+        let Modifiers {
+            alt,
+            ctrl,
+            mac_cmd,
+            command,
+            ..
+        } = shortcut.modifiers;
+        if !(alt || ctrl || mac_cmd || command) && self.wants_keyboard_input() {
+            return false;
+        }
+        let mut frame_state = self.frame_state();
+        if frame_state.consumed_shortcuts.contains(shortcut) {
+            false
+        } else {
+            frame_state.consumed_shortcuts.push(*shortcut);
+            true
+        }
+    }
+
+    /// The number of frames that have been started, i.e. `1` during the first frame.
+    ///
+    /// Time, and time since last frame, are in [`InputState::time`] and [`InputState::unstable_dt`].
+    #[inline(always)]
+    pub fn frame_nr(&self) -> u64 {
+        self.input.frame_nr
+    }
+
     /// Not valid until first call to [`CtxRef::begin_frame()`].
     /// That's because since we don't know the proper `pixels_per_point` until then.
     pub fn fonts(&self) -> &Fonts {
@@ -423,7 +520,25 @@ impl Context {
     /// The default `egui` fonts only support latin and cyrillic alphabets,
     /// but you can call this to install additional fonts that support e.g. korean characters.
     ///
-    /// The new fonts will become active at the start of the next frame.
+    /// The new fonts will become active at the start of the next frame,
+    /// and all text will then be laid out again with them.
+    /// This can be called at any time, e.g. when the user has picked a font file:
+    ///
+    /// ```no_run
+    /// # let ctx = egui::CtxRef::default();
+    /// let mut fonts = egui::FontDefinitions::default();
+    /// let ttf = std::fs::read("NanumGothic.ttf").unwrap();
+    /// fonts
+    ///     .font_data
+    ///     .insert("nanum".to_owned(), std::borrow::Cow::Owned(ttf));
+    /// // Use it for the characters that are missing from the default fonts:
+    /// fonts
+    ///     .fonts_for_family
+    ///     .get_mut(&egui::FontFamily::Proportional)
+    ///     .unwrap()
+    ///     .push("nanum".to_owned());
+    /// ctx.set_fonts(fonts);
+    /// ```
     pub fn set_fonts(&self, font_definitions: FontDefinitions) {
         if let Some(current_fonts) = &self.fonts {
             // NOTE: this comparison is expensive since it checks TTF data for equality
@@ -485,8 +600,7 @@ impl Context {
 
     /// Useful for pixel-perfect rendering
     pub(crate) fn round_to_pixel(&self, point: f32) -> f32 {
-        let pixels_per_point = self.pixels_per_point();
-        (point * pixels_per_point).round() / pixels_per_point
+        emath::round_to_pixel(point, self.pixels_per_point())
     }
 
     /// Useful for pixel-perfect rendering
@@ -703,6 +817,19 @@ impl Context {
         }
     }
 
+    /// A copy of the shapes painted to the given layer so far this frame, in the order they were added.
+    ///
+    /// Useful for inspecting what is being painted, e.g. in tests.
+    /// All shapes are handed over (and removed) by [`Context::end_frame`].
+    pub fn layer_shapes(&self, layer_id: LayerId) -> Vec<ClippedShape> {
+        self.graphics()
+            .list(layer_id)
+            .lock()
+            .iter()
+            .cloned()
+            .collect()
+    }
+
     /// Top-most layer at the given position.
     pub fn layer_id_at(&self, pos: Pos2) -> Option<LayerId> {
         let resize_grab_radius_side = self.style().interaction.resize_grab_radius_side;
@@ -711,7 +838,9 @@ impl Context {
 
     pub(crate) fn rect_contains_pointer(&self, layer_id: LayerId, rect: Rect) -> bool {
         if let Some(pointer_pos) = self.input.pointer.interact_pos() {
-            rect.contains(pointer_pos) && self.layer_id_at(pointer_pos) == Some(layer_id)
+            rect.contains_half_open(pointer_pos)
+                && self.layer_id_at(pointer_pos) == Some(layer_id)
+                && !self.memory().is_behind_modal(layer_id)
         } else {
             false
         }
@@ -724,6 +853,108 @@ impl Context {
         self.memory().options.style.debug.debug_on_hover
     }
 
+    /// `Id`:s that have been used by more than one widget so far this frame (or last frame,
+    /// if called between [`Context::end_frame`] and [`CtxRef::begin_frame`]).
+    ///
+    /// These are also shown as errors on screen.
+    /// A clash usually means two things like `Window`s or `CollapsingHeader`s share the same name,
+    /// and can be fixed by wrapping one of them in [`Ui::push_id`]:
+    ///
+    /// ```
+    /// let mut ctx = egui::CtxRef::default();
+    /// ctx.begin_frame(Default::default());
+    /// egui::CentralPanel::default().show(&ctx, |ui| {
+    ///     ui.collapsing("Same name", |ui| ui.label("First"));
+    ///     ui.collapsing("Same name", |ui| ui.label("Second"));
+    /// });
+    /// assert_eq!(ctx.id_clashes().len(), 1);
+    ///
+    /// ctx.begin_frame(Default::default());
+    /// egui::CentralPanel::default().show(&ctx, |ui| {
+    ///     ui.collapsing("Same name", |ui| ui.label("First"));
+    ///     ui.push_id("second", |ui| ui.collapsing("Same name", |ui| ui.label("Second")));
+    /// });
+    /// assert!(ctx.id_clashes().is_empty());
+    /// ```
+    pub fn id_clashes(&self) -> Vec<Id> {
+        self.frame_state().id_clashes.clone()
+    }
+
+    /// All `Id`:s that have been used for interaction so far this frame (or last frame,
+    /// if called between [`Context::end_frame`] and [`CtxRef::begin_frame`]), grouped by layer.
+    ///
+    /// `Id`:s used by several widgets at different places are marked with `DUPLICATE`.
+    ///
+    /// Only recorded when [`crate::style::DebugOptions::record_ids`] is turned on,
+    /// otherwise this returns an empty string.
+    ///
+    /// ```
+    /// let mut ctx = egui::CtxRef::default();
+    /// let mut style = (*ctx.style()).clone();
+    /// style.debug.record_ids = true;
+    /// ctx.set_style(style);
+    ///
+    /// ctx.begin_frame(Default::default());
+    /// egui::CentralPanel::default().show(&ctx, |ui| {
+    ///     ui.button("Click me");
+    /// });
+    /// assert!(ctx.debug_id_tree().contains("click"));
+    /// ```
+    pub fn debug_id_tree(&self) -> String {
+        use std::fmt::Write as _;
+
+        let recorded_ids = self.frame_state().recorded_ids.clone();
+
+        let mut first_rect: std::collections::HashMap<Id, Rect> = Default::default();
+        let mut duplicates: std::collections::HashSet<Id> = Default::default();
+        for &(_, id, rect, _) in &recorded_ids {
+            let first_rect = *first_rect.entry(id).or_insert(rect);
+            // Same rule as for the on-screen warning in `register_interaction_id`:
+            if !first_rect.expand(0.1).contains_rect(rect)
+                && !rect.expand(0.1).contains_rect(first_rect)
+            {
+                duplicates.insert(id);
+            }
+        }
+
+        let mut layers: Vec<LayerId> = vec![];
+        for (layer_id, ..) in &recorded_ids {
+            if !layers.contains(layer_id) {
+                layers.push(*layer_id);
+            }
+        }
+        layers.sort_by_key(|layer_id| layer_id.order);
+
+        let mut tree = String::new();
+        for layer_id in layers {
+            writeln!(tree, "{}", layer_id.short_debug_format()).ok();
+            for &(_, id, rect, sense) in recorded_ids.iter().filter(|r| r.0 == layer_id) {
+                let kind = match (sense.click, sense.drag) {
+                    (true, true) => "click+drag",
+                    (true, false) => "click",
+                    (false, true) => "drag",
+                    (false, false) => "hover",
+                };
+                write!(
+                    tree,
+                    "  {} {:10} [{:.0} {:.0} - {:.0} {:.0}]",
+                    id.short_debug_format(),
+                    kind,
+                    rect.min.x,
+                    rect.min.y,
+                    rect.max.x,
+                    rect.max.y
+                )
+                .ok();
+                if duplicates.contains(&id) {
+                    tree += " DUPLICATE";
+                }
+                tree += "\n";
+            }
+        }
+        tree
+    }
+
     /// Turn on/off wether or not to debug widget layout on hover.
     pub fn set_debug_on_hover(&self, debug_on_hover: bool) {
         let mut style = (*self.memory().options.style).clone();
@@ -958,3 +1189,76 @@ impl Context {
         self.set_style(style);
     }
 }
+
+#[cfg(test)]
+#[test]
+fn plain_shortcuts_are_ignored_while_typing() {
+    let mut ctx = CtxRef::default();
+    let mut text = String::new();
+    let modifiers = Modifiers {
+        ctrl: true,
+        command: true,
+        ..Default::default()
+    };
+    let plain = Shortcut::new(Default::default(), Key::S);
+    let save = Shortcut::command(Key::S);
+    for frame in 0..3 {
+        let mut raw_input = RawInput::default();
+        if frame == 2 {
+            raw_input.events = vec![
+                Event::Key {
+                    key: Key::S,
+                    pressed: true,
+                    modifiers: Default::default(),
+                },
+                Event::Key {
+                    key: Key::S,
+                    pressed: true,
+                    modifiers,
+                },
+            ];
+        }
+        ctx.begin_frame(raw_input);
+        CentralPanel::default().show(&ctx, |ui| {
+            let response = ui.text_edit_singleline(&mut text);
+            if frame == 0 {
+                response.request_focus();
+            }
+        });
+        if frame == 2 {
+            assert!(!ctx.consume_shortcut(&plain), "typing into the TextEdit");
+            assert!(ctx.consume_shortcut(&save));
+        }
+        let _ = ctx.end_frame();
+    }
+    ctx.memory().clear_focus();
+    ctx.begin_frame(RawInput {
+        events: vec![Event::Key {
+            key: Key::S,
+            pressed: true,
+            modifiers: Default::default(),
+        }],
+        ..Default::default()
+    });
+    assert!(ctx.consume_shortcut(&plain));
+    let _ = ctx.end_frame();
+}
+
+#[cfg(test)]
+#[test]
+fn many_labels_are_tessellated_into_one_mesh() {
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(Default::default());
+    CentralPanel::default().show(&ctx, |ui| {
+        for i in 0..100 {
+            ui.label(format!("Label {}", i));
+        }
+    });
+    let (_, shapes) = ctx.end_frame();
+    let num_texts = shapes
+        .iter()
+        .filter(|clipped| matches!(clipped.1, Shape::Text(_)))
+        .count();
+    assert_eq!(num_texts, 100);
+    assert_eq!(ctx.tessellate(shapes).len(), 1);
+}
@@ -10,6 +10,10 @@ use crate::emath::*;
 /// [`crate::Context::wants_pointer_input`] and [`crate::Context::wants_keyboard_input`].
 ///
 /// All coordinates are in points (logical pixels) with origin (0, 0) in the top left corner.
+///
+/// The pointer is only reported through [`Self::events`]:
+/// until the first [`Event::PointerMoved`] (and after [`Event::PointerGone`]) there is no pointer,
+/// so `RawInput::default()` means "no input" and nothing is hovered.
 #[derive(Clone, Debug)]
 pub struct RawInput {
     /// How many points (logical pixels) the user scrolled
@@ -53,6 +57,21 @@ pub struct RawInput {
     /// There is currently no way to know if egui handles a particular event,
     /// but you can check if egui is using the keyboard with [`crate::Context::wants_keyboard_input`]
     /// and/or the pointer (mouse/touch) with [`crate::Context::is_using_pointer`].
+    ///
+    /// The integration pushes one [`Event`] for each key press, release, text input etc.
+    /// Widgets then read them through [`crate::InputState`]:
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// let mut raw_input = egui::RawInput::default();
+    /// raw_input.events.push(egui::Event::Key {
+    ///     key: egui::Key::Enter,
+    ///     pressed: true,
+    ///     modifiers: Default::default(),
+    /// });
+    /// ctx.begin_frame(raw_input);
+    /// assert!(ctx.input().key_pressed(egui::Key::Enter));
+    /// ```
     pub events: Vec<Event>,
 
     /// Dragged files hovering over egui.
@@ -159,10 +178,15 @@ pub enum Event {
     PointerGone,
 
     /// IME composition start.
+    ///
+    /// An integration should forward the platform input method events as
+    /// `CompositionStart`, then a `CompositionUpdate` for each change of the candidate text,
+    /// and finally a `CompositionEnd` with the committed text.
+    /// A focused [`crate::TextEdit`] shows the candidate text underlined until it is committed.
     CompositionStart,
-    /// A new IME candidate is being suggested.
+    /// A new IME candidate is being suggested. Replaces the previous candidate.
     CompositionUpdate(String),
-    /// IME composition ended with this final result.
+    /// IME composition ended with this final result, which replaces the candidate.
     CompositionEnd(String),
 
     /// On touch screens, report this *in addition to*
@@ -312,6 +336,81 @@ pub enum Key {
     Z, // Used for cmd+Z (undo)
 }
 
+/// A keyboard shortcut, e.g. `Ctrl+S`, for use with [`crate::Context::consume_shortcut`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Shortcut {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl Shortcut {
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// The key together with [`Modifiers::command`], i.e. `Ctrl` on Windows and Linux and `⌘` on Mac.
+    pub fn command(key: Key) -> Self {
+        let modifiers = Modifiers {
+            command: true,
+            ..Default::default()
+        };
+        Self::new(modifiers, key)
+    }
+
+    /// Are these the modifiers of a key event that should trigger this shortcut?
+    ///
+    /// `alt`, `shift` and `command` must match exactly,
+    /// while `ctrl` and `mac_cmd` only need to be down if the shortcut asks for them.
+    pub fn matches_modifiers(&self, modifiers: Modifiers) -> bool {
+        self.modifiers.alt == modifiers.alt
+            && self.modifiers.shift == modifiers.shift
+            && self.modifiers.command == modifiers.command
+            && (!self.modifiers.ctrl || modifiers.ctrl)
+            && (!self.modifiers.mac_cmd || modifiers.mac_cmd)
+    }
+
+    /// A human-readable description, e.g. `"Ctrl+Shift+S"` (or `"⇧⌘S"` if `is_mac`).
+    ///
+    /// ```
+    /// # use egui::{Key, Shortcut};
+    /// assert_eq!(Shortcut::command(Key::S).format(false), "Ctrl+S");
+    /// assert_eq!(Shortcut::command(Key::Num1).format(true), "⌘1");
+    /// ```
+    pub fn format(&self, is_mac: bool) -> String {
+        let Modifiers {
+            alt,
+            ctrl,
+            shift,
+            mac_cmd,
+            command,
+        } = self.modifiers;
+        let key = format!("{:?}", self.key);
+        let key = key.strip_prefix("Num").unwrap_or(&key);
+
+        let mut s = String::new();
+        if is_mac {
+            for (down, symbol) in [
+                (ctrl, "⌃"),
+                (alt, "⌥"),
+                (shift, "⇧"),
+                (mac_cmd || command, "⌘"),
+            ] {
+                if down {
+                    s += symbol;
+                }
+            }
+        } else {
+            for (down, name) in [(ctrl || command, "Ctrl+"), (alt, "Alt+"), (shift, "Shift+")] {
+                if down {
+                    s += name;
+                }
+            }
+        }
+        s.push_str(key);
+        s
+    }
+}
+
 impl RawInput {
     pub fn ui(&self, ui: &mut crate::Ui) {
         let Self {
@@ -9,6 +9,17 @@ pub(crate) struct FrameState {
     /// Used to debug `Id` clashes of widgets.
     pub(crate) used_ids: ahash::AHashMap<Id, Rect>,
 
+    /// `Id`:s that were used by more than one widget this frame.
+    pub(crate) id_clashes: Vec<Id>,
+
+    /// All interactions this frame, in order.
+    /// Only filled in if [`crate::style::DebugOptions::record_ids`] is on.
+    pub(crate) recorded_ids: Vec<(LayerId, Id, Rect, Sense)>,
+
+    /// Shortcuts that have already been acted upon this frame.
+    /// See [`Context::consume_shortcut`].
+    pub(crate) consumed_shortcuts: Vec<Shortcut>,
+
     /// Starts off as the screen_rect, shrinks as panels are added.
     /// The `CentralPanel` does not change this.
     /// This is the area available to Window's.
@@ -36,6 +47,9 @@ impl Default for FrameState {
     fn default() -> Self {
         Self {
             used_ids: Default::default(),
+            id_clashes: Default::default(),
+            recorded_ids: Default::default(),
+            consumed_shortcuts: Default::default(),
             available_rect: Rect::NAN,
             unused_rect: Rect::NAN,
             used_by_panels: Rect::NAN,
@@ -50,6 +64,9 @@ impl FrameState {
     pub(crate) fn begin_frame(&mut self, input: &InputState) {
         let Self {
             used_ids,
+            id_clashes,
+            recorded_ids,
+            consumed_shortcuts,
             available_rect,
             unused_rect,
             used_by_panels,
@@ -59,6 +76,9 @@ impl FrameState {
         } = self;
 
         used_ids.clear();
+        id_clashes.clear();
+        recorded_ids.clear();
+        consumed_shortcuts.clear();
         *available_rect = input.screen_rect();
         *unused_rect = input.screen_rect();
         *used_by_panels = Rect::NOTHING;
@@ -45,7 +45,10 @@ pub struct InputState {
     /// Time in seconds. Relative to whatever. Used for animation.
     pub time: f64,
 
-    /// Time since last frame, in seconds.
+    /// The number of frames that have been started, i.e. `1` during the first frame.
+    pub frame_nr: u64,
+
+    /// Time since last frame, in seconds. Never negative.
     ///
     /// This can be very unstable in reactive mode (when we don't paint each frame)
     /// so it can be smart to use e.g. `unstable_dt.min(1.0 / 30.0)`.
@@ -75,6 +78,7 @@ impl Default for InputState {
             screen_rect: Rect::from_min_size(Default::default(), vec2(10_000.0, 10_000.0)),
             pixels_per_point: 1.0,
             time: 0.0,
+            frame_nr: 0,
             unstable_dt: 1.0 / 6.0,
             predicted_dt: 1.0 / 6.0,
             modifiers: Default::default(),
@@ -90,7 +94,7 @@ impl InputState {
         let time = new
             .time
             .unwrap_or_else(|| self.time + new.predicted_dt as f64);
-        let unstable_dt = (time - self.time) as f32;
+        let unstable_dt = (time - self.time).max(0.0) as f32; // in case the backend clock jumps back
         let screen_rect = new.screen_rect.unwrap_or(self.screen_rect);
         self.create_touch_states_for_new_devices(&new.events);
         for touch_state in self.touch_states.values_mut() {
@@ -114,6 +118,7 @@ impl InputState {
             screen_rect,
             pixels_per_point: new.pixels_per_point.unwrap_or(self.pixels_per_point),
             time,
+            frame_nr: self.frame_nr + 1,
             unstable_dt,
             predicted_dt: new.predicted_dt,
             modifiers: new.modifiers,
@@ -212,6 +217,16 @@ impl InputState {
         })
     }
 
+    /// Was the given keyboard shortcut pressed this frame?
+    ///
+    /// Unlike [`crate::Context::consume_shortcut`], this returns `true` for every caller.
+    pub fn shortcut_pressed(&self, shortcut: &Shortcut) -> bool {
+        self.events.iter().any(|event| {
+            matches!(event, Event::Key { key, pressed: true, modifiers }
+                if *key == shortcut.key && shortcut.matches_modifiers(*modifiers))
+        })
+    }
+
     /// Also known as device pixel ratio, > 1 for high resolution screens.
     #[inline(always)]
     pub fn pixels_per_point(&self) -> f32 {
@@ -664,6 +679,7 @@ impl InputState {
             screen_rect,
             pixels_per_point,
             time,
+            frame_nr,
             unstable_dt,
             predicted_dt,
             modifiers,
@@ -693,6 +709,7 @@ impl InputState {
             pixels_per_point
         ));
         ui.label(format!("time: {:.3} s", time));
+        ui.label(format!("frame_nr: {}", frame_nr));
         ui.label(format!(
             "time since previous frame: {:.1} ms",
             1e3 * unstable_dt
@@ -740,3 +757,74 @@ impl PointerState {
         ui.label(format!("pointer_events: {:?}", pointer_events));
     }
 }
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+#[test]
+fn frame_nr_and_dt() {
+    let mut input = InputState::default();
+    for (i, &time) in [1.0, 2.0, 1.5].iter().enumerate() {
+        input = input.begin_frame(RawInput {
+            time: Some(time),
+            ..Default::default()
+        });
+        assert_eq!(input.frame_nr, i as u64 + 1);
+        assert!(input.unstable_dt >= 0.0);
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn no_pointer_until_it_moves() {
+    let mut input = InputState::default().begin_frame(RawInput::default());
+    assert_eq!(input.pointer.hover_pos(), None, "not at the origin");
+
+    input = input.begin_frame(RawInput {
+        events: vec![Event::PointerMoved(pos2(10.0, 20.0))],
+        ..Default::default()
+    });
+    assert_eq!(input.pointer.hover_pos(), Some(pos2(10.0, 20.0)));
+
+    input = input.begin_frame(RawInput {
+        events: vec![Event::PointerGone],
+        ..Default::default()
+    });
+    assert_eq!(input.pointer.hover_pos(), None);
+}
+
+#[cfg(test)]
+#[test]
+fn shortcut_pressed_with_and_without_shift() {
+    let press = |shift: bool| {
+        InputState::default().begin_frame(RawInput {
+            events: vec![Event::Key {
+                key: Key::S,
+                pressed: true,
+                modifiers: Modifiers {
+                    ctrl: true,
+                    command: true,
+                    shift,
+                    ..Default::default()
+                },
+            }],
+            ..Default::default()
+        })
+    };
+    let save = Shortcut::command(Key::S);
+    let save_as = Shortcut::new(
+        Modifiers {
+            shift: true,
+            ..save.modifiers
+        },
+        Key::S,
+    );
+
+    let input = press(false);
+    assert!(input.shortcut_pressed(&save));
+    assert!(!input.shortcut_pressed(&save_as));
+
+    let input = press(true);
+    assert!(!input.shortcut_pressed(&save));
+    assert!(input.shortcut_pressed(&save_as));
+}
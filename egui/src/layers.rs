@@ -119,6 +119,17 @@ impl PaintList {
         self.0.is_empty()
     }
 
+    /// The number of shapes in the list.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The shapes in the order they were added, i.e. back-to-front.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &ClippedShape> {
+        self.0.iter()
+    }
+
     /// Returns the index of the new [`Shape`] that can be used with `PaintList::set`.
     #[inline(always)]
     pub fn add(&mut self, clip_rect: Rect, shape: Shape) -> ShapeIdx {
@@ -132,6 +143,11 @@ impl PaintList {
             .extend(shapes.drain(..).map(|shape| ClippedShape(clip_rect, shape)))
     }
 
+    /// Move all shapes from `other` to the end of this list, leaving `other` empty.
+    pub fn append(&mut self, other: &mut PaintList) {
+        self.0.append(&mut other.0);
+    }
+
     /// Modify an existing [`Shape`].
     ///
     /// Sometimes you want to paint a frame behind some contents, but don't know how large the frame needs to be
@@ -144,6 +160,21 @@ impl PaintList {
         self.0[idx.0] = ClippedShape(clip_rect, shape);
     }
 
+    /// The index the next added [`Shape`] will get.
+    #[inline(always)]
+    pub fn next_idx(&self) -> ShapeIdx {
+        ShapeIdx(self.0.len())
+    }
+
+    /// Multiply the opacity of every [`Shape`] from `start` and onwards, in-place.
+    pub fn multiply_opacity_from(&mut self, start: ShapeIdx, opacity: f32) {
+        for ClippedShape(_, shape) in &mut self.0[start.0..] {
+            epaint::shape_transform::adjust_colors(shape, &|color| {
+                *color = color.linear_multiply(opacity);
+            });
+        }
+    }
+
     /// Translate each [`Shape`] and clip rectangle by this much, in-place
     pub fn translate(&mut self, delta: Vec2) {
         for ClippedShape(clip_rect, shape) in &mut self.0 {
@@ -163,6 +194,12 @@ impl GraphicLayers {
             .or_default()
     }
 
+    /// All shapes in back-to-front order, leaving the layers empty.
+    ///
+    /// The layers are painted by [`Order`], and layers of the same [`Order`] as in `area_order`
+    /// (with any layers not in `area_order` after those).
+    /// Within a layer, shapes keep the order they were added in,
+    /// so e.g. a frame is painted behind the widgets in it.
     pub fn drain(&mut self, area_order: &[LayerId]) -> impl ExactSizeIterator<Item = ClippedShape> {
         let mut all_shapes: Vec<_> = Default::default();
 
@@ -192,3 +229,61 @@ impl GraphicLayers {
         all_shapes.into_iter()
     }
 }
+
+#[cfg(test)]
+#[test]
+fn shapes_are_drained_back_to_front() {
+    let mut ctx = CtxRef::default();
+    let mut shapes = vec![];
+    for _ in 0..2 {
+        // The tooltip is added first, but should still be painted on top:
+        ctx.begin_frame(Default::default());
+        crate::containers::show_tooltip_at(&ctx, Id::new("tooltip"), Some(Pos2::ZERO), |ui| {
+            ui.label("tooltip")
+        });
+        crate::CentralPanel::default().show(&ctx, |ui| {
+            ui.group(|ui| ui.label("grouped"));
+        });
+        shapes = ctx.end_frame().1;
+    }
+
+    let names: Vec<String> = shapes
+        .iter()
+        .map(|ClippedShape(_, shape)| match shape {
+            Shape::Rect { .. } => "rect".to_owned(),
+            Shape::Text(text) => text.galley.text().to_owned(),
+            _ => "other".to_owned(),
+        })
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "rect",    // background of the central panel
+            "rect",    // frame of the group…
+            "grouped", // …painted before its contents
+            "other",   // frame and shadow of the tooltip, which is painted last
+            "tooltip",
+        ]
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn layer_shapes_can_be_inspected_mid_frame() {
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(Default::default());
+    crate::CentralPanel::default().show(&ctx, |ui| {
+        let layer_id = ui.layer_id();
+        let num_shapes = ui.ctx().layer_shapes(layer_id).len();
+        ui.label("one");
+        ui.label("two");
+        let shapes = ui.ctx().layer_shapes(layer_id);
+        assert_eq!(shapes.len(), num_shapes + 2);
+        assert!(
+            matches!(&shapes.last().unwrap().1, Shape::Text(text) if text.galley.text() == "two")
+        );
+    });
+    let (_, shapes) = ctx.end_frame();
+    assert!(!shapes.is_empty());
+    assert!(ctx.layer_shapes(LayerId::background()).is_empty());
+}
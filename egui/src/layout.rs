@@ -780,3 +780,21 @@ impl Layout {
         painter.debug_text(next_pos, align, stroke.color, text);
     }
 }
+
+#[cfg(test)]
+#[test]
+fn right_to_left_places_widgets_from_the_right() {
+    use crate::{CentralPanel, CtxRef};
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(Default::default());
+    CentralPanel::default().show(&ctx, |ui| {
+        let right = ui.max_rect().right();
+        ui.with_layout(Layout::right_to_left(), |ui| {
+            let first = ui.button("First").rect;
+            let second = ui.button("Second").rect;
+            assert_eq!(first.right(), right);
+            assert!(second.right() < first.left());
+        });
+    });
+    let _ = ctx.end_frame();
+}
@@ -69,8 +69,33 @@ pub struct Memory {
     #[cfg_attr(feature = "persistence", serde(skip))]
     popup: Option<Id>,
 
+    /// The layer of the [`crate::Modal`] shown this frame and last frame (if any).
+    /// Everything in a lower [`crate::Order`] ignores the pointer while a modal is shown.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    modal: Option<LayerId>,
+
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    modal_last_frame: Option<LayerId>,
+
+    /// Which modal is open (if any), for [`crate::Modal::show_if_open`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    open_modal: Option<Id>,
+
     #[cfg_attr(feature = "persistence", serde(skip))]
     everything_is_visible: bool,
+
+    /// When did the pointer start hovering the widgets that want to show a tooltip?
+    /// Used for [`crate::style::Interaction::tooltip_delay`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) tooltip_hover_start: HashMap<Id, f64>,
+
+    /// The [`InputState::frame_nr`] of the last finished frame, used for [`Self::gc`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    last_frame_nr: u64,
+
+    /// The last frame each id in `id_data`/`id_data_temp` was used by a widget.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    id_last_seen: HashMap<Id, u64>,
 }
 
 // ----------------------------------------------------------------------------
@@ -279,6 +304,21 @@ impl Memory {
         if !prev_input.pointer.any_down() {
             self.window_interaction = None;
         }
+
+        let escape_pressed = new_input.events.iter().any(|event| {
+            matches!(
+                event,
+                crate::Event::Key {
+                    key: crate::Key::Escape,
+                    pressed: true,
+                    ..
+                }
+            )
+        });
+        if escape_pressed {
+            // Keyboard focus is surrendered in `Focus::begin_frame`.
+            self.close_popup();
+        }
     }
 
     pub(crate) fn end_frame(
@@ -288,7 +328,49 @@ impl Memory {
     ) {
         self.areas.end_frame();
         self.interaction.focus.end_frame(used_ids);
+        self.modal_last_frame = self.modal.take();
         self.drag_value.end_frame(input);
+        self.tooltip_hover_start
+            .retain(|id, _| used_ids.contains_key(id));
+
+        for id in used_ids.keys() {
+            if self.id_data.contains_key(id) || self.id_data_temp.contains_key(id) {
+                self.id_last_seen.insert(*id, input.frame_nr);
+            }
+        }
+        self.last_frame_nr = input.frame_nr;
+    }
+
+    /// Forget the `id_data`/`id_data_temp` of all widgets that haven't been
+    /// interacted with (e.g. shown) during the last `max_age_in_frames` frames.
+    ///
+    /// Call this occasionally in long-running apps that create lots of short-lived ids
+    /// (e.g. a list of collapsing headers with changing content),
+    /// or the memory usage of `Memory` will keep growing.
+    ///
+    /// Only state keyed by the same `Id` the widget interacts with is tracked,
+    /// and only once that widget has been interacted with while it has state.
+    /// So state stored under an `Id` that is never interacted with (e.g. the state of a `ScrollArea`),
+    /// and state restored by persistence whose widget isn't shown again, is never removed.
+    /// Note that the state of widgets in hidden windows will be forgotten too!
+    ///
+    /// `gc(u64::MAX)` never forgets anything.
+    pub fn gc(&mut self, max_age_in_frames: u64) {
+        let last_frame_nr = self.last_frame_nr;
+        let mut stale = vec![];
+        self.id_last_seen.retain(|id, last_seen| {
+            let keep = last_frame_nr <= last_seen.saturating_add(max_age_in_frames);
+            if !keep {
+                stale.push(*id);
+            }
+            keep
+        });
+
+        if !stale.is_empty() {
+            let stale: HashSet<Id> = stale.into_iter().collect();
+            self.id_data.retain(|id| !stale.contains(id));
+            self.id_data_temp.retain(|id| !stale.contains(id));
+        }
     }
 
     /// Top-most layer at the given position.
@@ -353,6 +435,15 @@ impl Memory {
         }
     }
 
+    /// Take keyboard focus away from whatever widget has it, e.g. when closing a dialog.
+    ///
+    /// Pressing escape does the same.
+    #[inline(always)]
+    pub fn clear_focus(&mut self) {
+        self.interaction.focus.id = None;
+        self.interaction.focus.is_focus_locked = false;
+    }
+
     /// Register this widget as being interested in getting keyboard focus.
     /// This will allow the user to select it with tab and shift-tab.
     #[inline(always)]
@@ -363,7 +454,7 @@ impl Memory {
     /// Stop editing of active `TextEdit` (if any).
     #[inline(always)]
     pub fn stop_text_input(&mut self) {
-        self.interaction.focus.id = None;
+        self.clear_focus();
     }
 
     #[inline(always)]
@@ -386,6 +477,7 @@ impl Memory {
 /// ## Popups
 /// Popups are things like combo-boxes, color pickers, menus etc.
 /// Only one can be be open at a time.
+/// Pressing escape closes the open popup.
 impl Memory {
     pub fn is_popup_open(&mut self, popup_id: Id) -> bool {
         self.popup == Some(popup_id) || self.everything_is_visible()
@@ -427,6 +519,43 @@ impl Memory {
     }
 }
 
+/// ## Modals
+/// A [`crate::Modal`] blocks the pointer from reaching anything below it.
+impl Memory {
+    /// Is a [`crate::Modal`] being shown (this frame or last frame)?
+    pub fn is_modal_active(&self) -> bool {
+        self.modal.or(self.modal_last_frame).is_some()
+    }
+
+    /// Is the [`crate::Modal`] with the given id open?
+    pub fn is_modal_open(&self, modal_id: Id) -> bool {
+        self.open_modal == Some(modal_id)
+    }
+
+    /// Open the [`crate::Modal`] with the given id, shown with [`crate::Modal::show_if_open`].
+    ///
+    /// The id is `Id::new(title)`, unless you used [`crate::Modal::id_source`].
+    pub fn open_modal(&mut self, modal_id: Id) {
+        self.open_modal = Some(modal_id);
+    }
+
+    pub fn close_modal(&mut self) {
+        self.open_modal = None;
+    }
+
+    pub(crate) fn set_modal(&mut self, layer_id: LayerId) {
+        self.modal = Some(layer_id);
+    }
+
+    /// Is the given layer behind a [`crate::Modal`], and so should ignore the pointer?
+    pub(crate) fn is_behind_modal(&self, layer_id: LayerId) -> bool {
+        match self.modal.or(self.modal_last_frame) {
+            Some(modal) => layer_id.order < modal.order,
+            None => false,
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Keeps track of `Area`s, which are free-floating `Ui`s.
@@ -466,6 +595,13 @@ impl Areas {
     pub(crate) fn set_state(&mut self, layer_id: LayerId, state: area::State) {
         self.visible_current_frame.insert(layer_id);
         self.areas.insert(layer_id.id, state);
+        self.add_to_order(layer_id);
+    }
+
+    /// A new layer is put above all known layers (of the same [`crate::Order`]).
+    ///
+    /// Called when an area begins, so that an area shown from within another area ends up above it.
+    pub(crate) fn add_to_order(&mut self, layer_id: LayerId) {
         if !self.order.iter().any(|x| *x == layer_id) {
             self.order.push(layer_id);
         }
@@ -546,3 +682,180 @@ fn memory_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Memory>();
 }
+
+#[cfg(test)]
+#[test]
+fn memory_gc() {
+    let mut memory = Memory::default();
+    let mut input = InputState::default();
+    let shown = Id::new("shown");
+    let gone = Id::new("gone");
+    let never_interacted = Id::new("never_interacted");
+    memory.id_data.insert(shown, 1_i32);
+    memory.id_data_temp.insert(gone, 2_i32);
+    memory.id_data.insert(never_interacted, 3_i32);
+
+    let mut used_ids = epaint::ahash::AHashMap::default();
+    used_ids.insert(shown, Rect::NOTHING);
+    used_ids.insert(gone, Rect::NOTHING);
+    input.frame_nr = 1;
+    memory.end_frame(&input, &used_ids);
+
+    used_ids.remove(&gone);
+    for frame_nr in 2..=11 {
+        input.frame_nr = frame_nr;
+        memory.end_frame(&input, &used_ids);
+    }
+    memory.gc(u64::MAX);
+    assert!(memory.id_data_temp.contains_key(&gone));
+
+    memory.gc(5);
+    assert_eq!(memory.id_data.get::<i32>(&shown), Some(&1));
+    assert!(!memory.id_data_temp.contains_key(&gone));
+    assert_eq!(
+        memory.id_data.get::<i32>(&never_interacted),
+        Some(&3),
+        "untracked state is kept"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn escape_closes_popup() {
+    let mut ctx = crate::CtxRef::default();
+    let mut combo_box_rect = Rect::NOTHING;
+    let mut popup_shown = false;
+    let click = |pos, pressed| crate::Event::PointerButton {
+        pos,
+        button: crate::PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    let escape = crate::Event::Key {
+        key: crate::Key::Escape,
+        pressed: true,
+        modifiers: Default::default(),
+    };
+
+    for frame in 0..5 {
+        let events = match frame {
+            1 => vec![click(combo_box_rect.center(), true)],
+            2 => vec![click(combo_box_rect.center(), false)],
+            4 => vec![escape.clone()],
+            _ => vec![],
+        };
+        ctx.begin_frame(crate::RawInput {
+            events,
+            ..Default::default()
+        });
+        popup_shown = false;
+        crate::CentralPanel::default().show(&ctx, |ui| {
+            combo_box_rect = crate::ComboBox::from_id_source("combo")
+                .show_ui(ui, |ui| {
+                    popup_shown = true;
+                    ui.label("Option");
+                })
+                .response
+                .rect;
+        });
+        let _ = ctx.end_frame();
+        if frame == 3 {
+            assert!(popup_shown, "clicking the combo box should open it");
+        }
+    }
+    assert!(!popup_shown, "escape should close the combo box");
+
+    let mut menu_button_pos = Pos2::ZERO;
+    let mut menu_shown = false;
+    for frame in 0..5 {
+        let events = match frame {
+            1 => vec![click(menu_button_pos, true)],
+            2 => vec![click(menu_button_pos, false)],
+            4 => vec![escape.clone()],
+            _ => vec![],
+        };
+        ctx.begin_frame(crate::RawInput {
+            events,
+            ..Default::default()
+        });
+        crate::CentralPanel::default().show(&ctx, |ui| {
+            crate::menu::bar(ui, |ui| {
+                // The bar takes the full width, so aim just inside its left edge:
+                menu_button_pos = ui.min_rect().left_center() + crate::vec2(4.0, 0.0);
+                menu_shown = crate::menu::menu(ui, "File", |ui| ui.label("Open")).is_some();
+            });
+        });
+        let _ = ctx.end_frame();
+        if frame == 3 {
+            assert!(menu_shown, "clicking the menu button should open it");
+        }
+    }
+    assert!(!menu_shown, "escape should close the menu");
+}
+
+#[cfg(test)]
+#[test]
+fn widget_state_survives_between_frames() {
+    #[derive(Clone, Debug, Default, PartialEq)]
+    #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+    struct KnobState {
+        drag_origin: Option<f32>,
+        turns: u32,
+    }
+
+    let mut ctx = crate::CtxRef::default();
+    let mut state = KnobState::default();
+    for _ in 0..3 {
+        ctx.begin_frame(Default::default());
+        crate::CentralPanel::default().show(&ctx, |ui| {
+            let id = ui.make_persistent_id("knob");
+            let mut memory = ui.memory();
+            let knob_state = memory.id_data.get_mut_or_default::<KnobState>(id);
+            knob_state.drag_origin = Some(0.5);
+            knob_state.turns += 1;
+            state = knob_state.clone();
+        });
+        let _ = ctx.end_frame();
+    }
+    assert_eq!(
+        state,
+        KnobState {
+            drag_origin: Some(0.5),
+            turns: 3
+        }
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn clear_focus_and_escape() {
+    let mut ctx = crate::CtxRef::default();
+    let mut text = String::new();
+    let escape = crate::Event::Key {
+        key: crate::Key::Escape,
+        pressed: true,
+        modifiers: Default::default(),
+    };
+    let mut focused = vec![];
+    for frame in 0..5 {
+        ctx.begin_frame(crate::RawInput {
+            events: if frame == 4 {
+                vec![escape.clone()]
+            } else {
+                vec![]
+            },
+            ..Default::default()
+        });
+        crate::CentralPanel::default().show(&ctx, |ui| {
+            let response = ui.text_edit_singleline(&mut text);
+            match frame {
+                0 | 3 => response.request_focus(),
+                2 => ui.memory().clear_focus(),
+                _ => {}
+            }
+            focused.push(response.has_focus());
+        });
+        let _ = ctx.end_frame();
+    }
+    assert_eq!(focused, [true, true, false, true, false]);
+}
@@ -19,6 +19,9 @@ use crate::{widgets::*, *};
 use epaint::Stroke;
 
 /// What is saved between frames.
+///
+/// The open menu is also the open popup of [`Memory`],
+/// so it is closed by escape or by opening another popup.
 #[derive(Clone, Copy, Debug, Default)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "persistence", serde(default))]
@@ -28,11 +31,31 @@ pub(crate) struct BarState {
 
 impl BarState {
     fn load(ctx: &Context, bar_id: &Id) -> Self {
-        *ctx.memory().id_data_temp.get_or_default(*bar_id)
+        let mut memory = ctx.memory();
+        let mut bar_state: Self = *memory.id_data_temp.get_or_default(*bar_id);
+        if let Some(open_menu) = bar_state.open_menu {
+            if !memory.is_popup_open(open_menu) {
+                bar_state.open_menu = None;
+            }
+        }
+        bar_state
     }
 
     fn save(self, ctx: &Context, bar_id: Id) {
-        ctx.memory().id_data_temp.insert(bar_id, self);
+        let mut memory = ctx.memory();
+        if let Some(open_menu) = self.open_menu {
+            memory.open_popup(open_menu);
+        }
+        memory.id_data_temp.insert(bar_id, self);
+    }
+
+    fn close_menu(&mut self, ctx: &Context) {
+        if let Some(open_menu) = self.open_menu.take() {
+            let mut memory = ctx.memory();
+            if memory.is_popup_open(open_menu) {
+                memory.close_popup();
+            }
+        }
     }
 }
 
@@ -127,7 +150,7 @@ fn menu_impl<'c, R>(
     if button_response.clicked() {
         // Toggle
         if bar_state.open_menu == Some(menu_id) {
-            bar_state.open_menu = None;
+            bar_state.close_menu(ui.ctx());
         } else {
             bar_state.open_menu = Some(menu_id);
         }
@@ -146,9 +169,10 @@ fn menu_impl<'c, R>(
         )
         .inner;
 
+        // Escape is handled by `Memory::begin_frame`, which closes the popup.
         // TODO: this prevents sub-menus in menus. We should fix that.
-        if ui.input().key_pressed(Key::Escape) || button_response.clicked_elsewhere() {
-            bar_state.open_menu = None;
+        if button_response.clicked_elsewhere() {
+            bar_state.close_menu(ui.ctx());
         }
         Some(inner)
     } else {
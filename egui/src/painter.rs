@@ -90,6 +90,36 @@ impl Painter {
     }
 }
 
+/// ## Detached painting
+impl Painter {
+    /// A copy of this `Painter` that paints into its own, private list of shapes.
+    ///
+    /// Nothing painted with the returned `Painter` will be visible
+    /// until it is moved back with [`Self::append`].
+    pub(crate) fn detached(&self) -> Self {
+        Self {
+            paint_list: Default::default(),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn next_shape_idx(&self) -> ShapeIdx {
+        self.paint_list.lock().next_idx()
+    }
+
+    /// Multiply the opacity of everything painted since `start` (see [`Self::next_shape_idx`]).
+    pub(crate) fn multiply_opacity_from(&self, start: ShapeIdx, opacity: f32) {
+        self.paint_list.lock().multiply_opacity_from(start, opacity);
+    }
+
+    /// Move everything painted by `other` to the end of what this `Painter` has painted.
+    pub(crate) fn append(&self, other: &Painter) {
+        if !std::sync::Arc::ptr_eq(&self.paint_list, &other.paint_list) {
+            self.paint_list.lock().append(&mut other.paint_list.lock());
+        }
+    }
+}
+
 /// ## Accessors etc
 impl Painter {
     /// Get a reference to the parent [`CtxRef`].
@@ -141,6 +171,12 @@ impl Painter {
     pub fn round_pos_to_pixels(&self, pos: Pos2) -> Pos2 {
         self.ctx().round_pos_to_pixels(pos)
     }
+
+    /// Useful for pixel-perfect rendering.
+    #[inline(always)]
+    pub fn round_rect_to_pixels(&self, rect: Rect) -> Rect {
+        self.ctx().round_rect_to_pixels(rect)
+    }
 }
 
 /// ## Low level
@@ -310,6 +346,23 @@ impl Painter {
         });
     }
 
+    /// A rectangle going smoothly from `top_color` to `bottom_color`, e.g. for a header.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let (rect, _) = ui.allocate_exact_size(egui::vec2(100.0, 20.0), egui::Sense::hover());
+    /// ui.painter()
+    ///     .vertical_gradient(rect, egui::Color32::LIGHT_BLUE, egui::Color32::DARK_BLUE);
+    /// ```
+    pub fn vertical_gradient(&self, rect: Rect, top_color: Color32, bottom_color: Color32) {
+        self.add(Shape::vertical_gradient(rect, top_color, bottom_color));
+    }
+
+    /// A rectangle going smoothly from `left_color` to `right_color`, e.g. for a color slider.
+    pub fn horizontal_gradient(&self, rect: Rect, left_color: Color32, right_color: Color32) {
+        self.add(Shape::horizontal_gradient(rect, left_color, right_color));
+    }
+
     /// Show an arrow starting at `origin` and going in the direction of `vec`, with the length `vec.length()`.
     pub fn arrow(&self, origin: Pos2, vec: Vec2, stroke: Stroke) {
         use crate::emath::*;
@@ -413,3 +466,25 @@ fn tint_shape_towards(shape: &mut Shape, target: Color32) {
         *color = crate::color::tint_color_towards(*color, target);
     });
 }
+
+#[cfg(test)]
+#[test]
+fn shapes_carry_the_clip_rect() {
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(Default::default());
+    let mut clip_rect = Rect::NOTHING;
+    crate::CentralPanel::default().show(&ctx, |ui| {
+        clip_rect = Rect::from_min_size(ui.cursor().min, crate::vec2(100.0, 10.0));
+        ui.set_clip_rect(clip_rect);
+        ui.add_space(100.0);
+        ui.label("Outside the clip rect");
+    });
+    let (_, shapes) = ctx.end_frame();
+
+    let label_clip_rects: Vec<Rect> = shapes
+        .iter()
+        .filter(|clipped| matches!(clipped.1, Shape::Text(_)))
+        .map(|clipped| clipped.0)
+        .collect();
+    assert_eq!(label_clip_rects, [clip_rect], "still painted, but clipped");
+}
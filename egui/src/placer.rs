@@ -5,6 +5,12 @@ pub(crate) struct Placer {
     grid: Option<grid::GridLayout>,
     layout: Layout,
     region: Region,
+    /// The bounding box of the widgets themselves, without the frames they were placed in.
+    widget_bounds: Rect,
+    /// In a [`Ui::horizontal_baseline`] row: the ascent of its text, taken from last frame.
+    row_ascent: Option<f32>,
+    /// The largest ascent of the text aligned with [`Self::baseline_padding`] so far.
+    max_text_ascent: f32,
 }
 
 impl Placer {
@@ -14,6 +20,9 @@ impl Placer {
             grid: None,
             layout,
             region,
+            widget_bounds: Rect::NOTHING,
+            row_ascent: None,
+            max_text_ascent: 0.0,
         }
     }
 
@@ -53,6 +62,34 @@ impl Placer {
         self.region.min_rect
     }
 
+    /// The bounding box of all widgets added so far, not including the frames they were
+    /// centered or aligned within. [`Rect::NOTHING`] if no widget has been added.
+    #[inline(always)]
+    pub(crate) fn widget_bounds(&self) -> Rect {
+        self.widget_bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn set_row_ascent(&mut self, row_ascent: f32) {
+        self.row_ascent = Some(row_ascent);
+    }
+
+    /// How far down to move text with the given ascent so its baseline lines up with the row.
+    /// Always zero outside of [`Ui::horizontal_baseline`].
+    pub(crate) fn baseline_padding(&mut self, ascent: f32) -> f32 {
+        if let Some(row_ascent) = self.row_ascent {
+            self.max_text_ascent = self.max_text_ascent.max(ascent);
+            (row_ascent - ascent).at_least(0.0)
+        } else {
+            0.0
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn max_text_ascent(&self) -> f32 {
+        self.max_text_ascent
+    }
+
     #[inline(always)]
     pub(crate) fn max_rect(&self) -> Rect {
         self.region.max_rect
@@ -173,6 +210,7 @@ impl Placer {
         }
 
         self.expand_to_include_rect(frame_rect); // e.g. for centered layouts: pretend we used whole frame
+        self.widget_bounds = self.widget_bounds.union(widget_rect);
 
         self.region.sanity_check();
     }
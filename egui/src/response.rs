@@ -53,6 +53,9 @@ pub struct Response {
     /// The widget was being dragged, but now it has been released.
     pub(crate) drag_released: bool,
 
+    /// Was a pointer button pressed down on this widget this frame?
+    pub(crate) pressed: bool,
+
     /// Is the pointer button currently down on this widget?
     /// This is true if the pointer is pressing down or dragging a widget
     pub(crate) is_pointer_button_down_on: bool,
@@ -81,6 +84,7 @@ impl std::fmt::Debug for Response {
             double_clicked,
             dragged,
             drag_released,
+            pressed,
             is_pointer_button_down_on,
             interact_pointer_pos,
             changed,
@@ -96,6 +100,7 @@ impl std::fmt::Debug for Response {
             .field("double_clicked", double_clicked)
             .field("dragged", dragged)
             .field("drag_released", drag_released)
+            .field("pressed", pressed)
             .field("is_pointer_button_down_on", is_pointer_button_down_on)
             .field("interact_pointer_pos", interact_pointer_pos)
             .field("changed", changed)
@@ -176,6 +181,9 @@ impl Response {
     }
 
     /// The pointer is hovering above this widget or the widget was clicked/tapped this frame.
+    ///
+    /// Only the visible part of the widget can be hovered:
+    /// anything outside the clip rect of the [`Ui`], or covered by a window, popup or other layer on top, is ignored.
     #[inline(always)]
     pub fn hovered(&self) -> bool {
         self.hovered
@@ -255,6 +263,38 @@ impl Response {
         }
     }
 
+    /// How much the user scrolled while hovering this widget, if it should change its value.
+    ///
+    /// Only used if [`crate::style::Interaction::scroll_changes_values`] is set.
+    /// Consumes the scroll so that no surrounding [`crate::ScrollArea`] uses it too.
+    pub(crate) fn take_value_scroll_delta(&self) -> Vec2 {
+        if self.hovered && self.ctx.style().interaction.scroll_changes_values {
+            std::mem::take(&mut self.ctx.frame_state().scroll_delta)
+        } else {
+            Vec2::ZERO
+        }
+    }
+
+    /// Like [`Self::drag_delta`], but only while dragged with the given button.
+    ///
+    /// For instance, to pan a canvas with the secondary (right) mouse button:
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut pan = egui::Vec2::ZERO;
+    /// let (response, painter) =
+    ///     ui.allocate_painter(ui.available_size_before_wrap(), egui::Sense::drag());
+    /// pan += response.drag_delta_by(egui::PointerButton::Secondary);
+    /// painter.circle_filled(response.rect.center() + pan, 8.0, egui::Color32::RED);
+    /// ```
+    pub fn drag_delta_by(&self, button: PointerButton) -> Vec2 {
+        if self.dragged_by(button) {
+            self.ctx.input().pointer.delta()
+        } else {
+            Vec2::ZERO
+        }
+    }
+
     /// Where the pointer (mouse/touch) were when when this widget was clicked or dragged.
     /// `None` if the widget is not being interacted with.
     pub fn interact_pointer_pos(&self) -> Option<Pos2> {
@@ -271,6 +311,18 @@ impl Response {
         }
     }
 
+    /// Was a pointer button pressed down on this widget this frame?
+    ///
+    /// Unlike [`Self::clicked`], which is only true once the button is released,
+    /// this is true on the very first frame of the interaction.
+    /// After that, [`Self::is_pointer_button_down_on`] stays true until the button is released.
+    ///
+    /// Note that the widget must be sensing clicks or drags.
+    #[inline(always)]
+    pub fn pressed(&self) -> bool {
+        self.pressed
+    }
+
     /// Is the pointer button currently down on this widget?
     /// This is true if the pointer is pressing down or dragging a widget
     #[inline(always)]
@@ -355,6 +407,7 @@ impl Response {
         }
 
         if !self.hovered || !self.ctx.input().pointer.has_pointer() {
+            self.ctx.memory().tooltip_hover_start.remove(&self.id);
             return false;
         }
 
@@ -374,6 +427,22 @@ impl Response {
             return false;
         }
 
+        let tooltip_delay = self.ctx.style().interaction.tooltip_delay;
+        if tooltip_delay > 0.0 {
+            let time = self.ctx.input().time;
+            let hover_start = *self
+                .ctx
+                .memory()
+                .tooltip_hover_start
+                .entry(self.id)
+                .or_insert(time);
+            if time - hover_start < tooltip_delay {
+                // wait for the delay to pass
+                self.ctx.request_repaint();
+                return false;
+            }
+        }
+
         true
     }
 
@@ -501,6 +570,7 @@ impl Response {
             ],
             dragged: self.dragged || other.dragged,
             drag_released: self.drag_released || other.drag_released,
+            pressed: self.pressed || other.pressed,
             is_pointer_button_down_on: self.is_pointer_button_down_on
                 || other.is_pointer_button_down_on,
             interact_pointer_pos: self.interact_pointer_pos.or(other.interact_pointer_pos),
@@ -568,3 +638,37 @@ impl<R> InnerResponse<R> {
         Self { inner, response }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn nothing_is_hovered_after_the_pointer_leaves() {
+    use crate::{pos2, Event, RawInput};
+    let mut ctx = CtxRef::default();
+    let events = vec![
+        vec![Event::PointerMoved(pos2(10.0, 10.0))],
+        vec![], // the area is only known from the second frame
+        vec![Event::PointerGone],
+        vec![],
+    ];
+    let mut hovered = vec![];
+    let mut shows_tooltip = vec![];
+    for events in events {
+        ctx.begin_frame(RawInput {
+            events,
+            ..Default::default()
+        });
+        crate::Area::new("area")
+            .fixed_pos(Pos2::ZERO)
+            .show(&ctx, |ui| {
+                hovered.push(ui.button("Button").on_hover_text("tooltip").hovered());
+            });
+        let (_, shapes) = ctx.end_frame();
+        shows_tooltip.push(shapes.iter().any(|clipped| {
+            matches!(&clipped.1, epaint::Shape::Text(text) if text.galley.text() == "tooltip")
+        }));
+    }
+    // A tap on a touch screen is followed by `PointerGone`,
+    // so the widget stays hovered for that one frame to be able to respond to the tap:
+    assert_eq!(hovered, [false, true, true, false]);
+    assert_eq!(shows_tooltip, [false, true, false, false]);
+}
@@ -90,6 +90,22 @@ pub struct Spacing {
     ///
     /// `item_spacing` is inserted _after_ adding a widget, so to increase the spacing between
     /// widgets `A` and `B` you need to change `item_spacing` before adding `A`.
+    ///
+    /// The spacing only moves the cursor, so it never becomes part of the size of the
+    /// surrounding [`Ui`]. There is no trailing gap after the last widget:
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let spacing = ui.spacing().item_spacing.y;
+    /// let one = ui.vertical(|ui| {
+    ///     ui.label("A");
+    /// }).response.rect.height();
+    /// let two = ui.vertical(|ui| {
+    ///     ui.label("A");
+    ///     ui.label("B");
+    /// }).response.rect.height();
+    /// assert_eq!(two, 2.0 * one + spacing);
+    /// ```
     pub item_spacing: Vec2,
 
     /// Horizontal and vertical padding within a window frame.
@@ -162,6 +178,19 @@ pub struct Interaction {
 
     /// If `false`, tooltips will show up anytime you hover anything, even is mouse is still moving
     pub show_tooltips_only_when_still: bool,
+
+    /// Only show tooltips after the pointer has been hovering a widget for this many seconds.
+    pub tooltip_delay: f64,
+
+    /// If `true`, pressing a [`crate::Slider`] only grabs it if you hit the handle,
+    /// and dragging then moves the handle relative to where you grabbed it.
+    /// If `false`, the value jumps to wherever you press on the slider.
+    pub slider_grab_handle: bool,
+
+    /// If `true`, scrolling while hovering a [`crate::Slider`] or [`crate::DragValue`] changes its value.
+    /// The hovered widget then takes precedence over any surrounding [`crate::ScrollArea`].
+    /// Off by default, so that scrolling through a long list of sliders doesn't change them by accident.
+    pub scroll_changes_values: bool,
 }
 
 /// Controls the visual style (colors etc) of egui.
@@ -217,8 +246,11 @@ pub struct Visuals {
     pub code_bg_color: Color32,
 
     pub window_corner_radius: f32,
+
+    /// Painted beneath each [`crate::Window`]. Use `Shadow::default()` for no shadow.
     pub window_shadow: Shadow,
 
+    /// Painted beneath menus, combo boxes and other popups. Use `Shadow::default()` for no shadow.
     pub popup_shadow: Shadow,
 
     pub resize_corner_size: f32,
@@ -351,6 +383,10 @@ pub struct DebugOptions {
     /// Show which widgets make their parent higher
     pub show_expand_height: bool,
     pub show_resize: bool,
+    /// Outline all widgets (blue), child `Ui`:s (green) and interactive areas (red)
+    pub show_widgets: bool,
+    /// Record all `Id`:s used for interaction, see [`crate::Context::debug_id_tree`]
+    pub record_ids: bool,
 }
 
 // ----------------------------------------------------------------------------
@@ -397,6 +433,9 @@ impl Default for Interaction {
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
             show_tooltips_only_when_still: false,
+            tooltip_delay: 0.0,
+            slider_grab_handle: false,
+            scroll_changes_values: false,
         }
     }
 }
@@ -720,6 +759,9 @@ impl Interaction {
             resize_grab_radius_side,
             resize_grab_radius_corner,
             show_tooltips_only_when_still,
+            tooltip_delay,
+            slider_grab_handle,
+            scroll_changes_values,
         } = self;
         ui.add(Slider::new(resize_grab_radius_side, 0.0..=20.0).text("resize_grab_radius_side"));
         ui.add(
@@ -729,6 +771,12 @@ impl Interaction {
             show_tooltips_only_when_still,
             "Only show tooltips if mouse is still",
         );
+        ui.add(Slider::new(tooltip_delay, 0.0..=2.0).text("tooltip_delay (s)"));
+        ui.checkbox(slider_grab_handle, "Sliders must be grabbed by the handle");
+        ui.checkbox(
+            scroll_changes_values,
+            "Scroll to change sliders and drag values",
+        );
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -910,6 +958,8 @@ impl DebugOptions {
             show_expand_width: debug_expand_width,
             show_expand_height: debug_expand_height,
             show_resize: debug_resize,
+            show_widgets,
+            record_ids,
         } = self;
 
         ui.checkbox(debug_on_hover, "Show debug info on hover");
@@ -922,6 +972,12 @@ impl DebugOptions {
             "Show which widgets make their parent higher",
         );
         ui.checkbox(debug_resize, "Debug Resize");
+        ui.checkbox(
+            show_widgets,
+            "Outline all widgets, child Ui:s and interactive areas",
+        );
+        ui.checkbox(record_ids, "Record all interactive Id:s")
+            .on_hover_text("Read them with Context::debug_id_tree");
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -332,6 +332,10 @@ impl Ui {
 
     /// Screen-space rectangle for clipping what we paint in this ui.
     /// This is used, for instance, to avoid painting outside a window that is smaller than its contents.
+    ///
+    /// Child uis inherit the clip rect, and widgets outside of it can't be hovered or clicked.
+    /// When nesting, you usually want to intersect with the current clip rect:
+    /// `ui.set_clip_rect(rect.intersect(ui.clip_rect()))`.
     pub fn set_clip_rect(&mut self, clip_rect: Rect) {
         self.painter.set_clip_rect(clip_rect);
     }
@@ -363,6 +367,9 @@ impl Ui {
     ///
     /// If a new widget doesn't fit within the `max_rect` then the
     /// `Ui` will make room for it by expanding both `min_rect` and `max_rect`.
+    ///
+    /// Unlike the cursor, this does not move as widgets are added,
+    /// so use `max_rect().left()` and `max_rect().right()` for the edges of the `Ui`.
     pub fn max_rect(&self) -> Rect {
         self.placer.max_rect()
     }
@@ -483,14 +490,34 @@ impl Ui {
     /// Shrinks as widgets allocate space and the cursor moves.
     /// A small size should be interpreted as "as little as possible".
     /// An infinite size should be interpreted as "as much as you want".
+    ///
+    /// To have a widget fill whatever is left, allocate all of it last.
+    /// To pin something to the bottom, put it in a [`TopBottomPanel`] before the [`CentralPanel`]:
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// egui::TopBottomPanel::bottom("status_bar").show(ctx, |ui| {
+    ///     ui.label("Status: all good");
+    /// });
+    /// egui::CentralPanel::default().show(ctx, |ui| {
+    ///     ui.heading("My list");
+    ///     let (rect, _response) = ui.allocate_exact_size(ui.available_size(), egui::Sense::hover());
+    ///     ui.painter().rect_filled(rect, 0.0, ui.visuals().extreme_bg_color);
+    ///     assert_eq!(rect.bottom(), ui.max_rect().bottom());
+    /// });
+    /// ```
     pub fn available_size(&self) -> Vec2 {
         self.placer.available_size()
     }
 
+    /// Same as `available_size().x`. See [`Self::available_size`].
     pub fn available_width(&self) -> f32 {
         self.available_size().x
     }
 
+    /// Same as `available_size().y`. See [`Self::available_size`].
     pub fn available_height(&self) -> f32 {
         self.available_size().y
     }
@@ -646,6 +673,7 @@ impl Ui {
         let too_high = desired_size.y > original_available.y;
 
         let rect = self.allocate_space_impl(desired_size);
+        self.debug_show_rect(rect, Color32::LIGHT_BLUE);
 
         if self.style().debug.debug_on_hover && self.rect_contains_pointer(rect) {
             let painter = self.ctx().debug_painter();
@@ -713,6 +741,7 @@ impl Ui {
         egui_assert!(!rect.any_nan());
         let item_spacing = self.spacing().item_spacing;
         self.placer.advance_after_rects(rect, rect, item_spacing);
+        self.debug_show_rect(rect, Color32::LIGHT_BLUE);
 
         if self.style().debug.debug_on_hover && self.rect_contains_pointer(rect) {
             let painter = self.ctx().debug_painter();
@@ -786,6 +815,7 @@ impl Ui {
 
         self.placer
             .advance_after_rects(final_child_rect, final_child_rect, item_spacing);
+        self.debug_show_rect(final_child_rect, Color32::GREEN);
 
         if self.style().debug.debug_on_hover && self.rect_contains_pointer(final_child_rect) {
             let painter = self.ctx().debug_painter();
@@ -849,6 +879,29 @@ impl Ui {
         (response, painter)
     }
 
+    /// Allocate exactly `desired_size` and get a child [`Ui`] placed there, e.g. for a custom widget with sub-widgets.
+    ///
+    /// Unlike with [`Self::allocate_ui`], the space is reserved up front,
+    /// so whatever is added to the child [`Ui`] does not change how much space is used.
+    /// Contents that do not fit are clipped.
+    ///
+    /// The [`Response`] is claimed before any sub-widget is added,
+    /// so use [`Sense::hover`] if the sub-widgets should be clickable.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let (response, mut card_ui) = ui.allocate_child_ui(egui::vec2(200.0, 80.0), egui::Sense::hover());
+    /// ui.painter().rect_filled(response.rect, 4.0, ui.visuals().faint_bg_color);
+    /// card_ui.heading("Title");
+    /// if card_ui.button("Open").clicked() { /* … */ }
+    /// ```
+    pub fn allocate_child_ui(&mut self, desired_size: Vec2, sense: Sense) -> (Response, Ui) {
+        let (rect, response) = self.allocate_exact_size(desired_size, sense);
+        let mut child_ui = self.child_ui(rect, *self.layout());
+        child_ui.set_clip_rect(self.clip_rect().intersect(rect));
+        (response, child_ui)
+    }
+
     /// Move the scroll to this cursor position with the specified alignment.
     ///
     /// ```
@@ -929,9 +982,15 @@ impl Ui {
     /// Add extra space before the next widget.
     ///
     /// The direction is dependent on the layout.
-    /// This will be in addition to the [`Spacing::item_spacing`}.
+    /// This will be in addition to the [`crate::style::Spacing::item_spacing`].
     ///
     /// [`Self::min_rect`] will expand to contain the space.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let height = ui.vertical(|ui| ui.add_space(32.0)).response.rect.height();
+    /// assert_eq!(height, 32.0);
+    /// ```
     #[inline(always)]
     pub fn add_space(&mut self, amount: f32) {
         self.placer.advance_cursor(amount);
@@ -954,6 +1013,40 @@ impl Ui {
         label.into().text_color(color).ui(self)
     }
 
+    /// Show an icon followed by some text, e.g. `ui.label_with_icon('⚠', "Careful")`.
+    ///
+    /// The icon is looked up in the fallback fonts (e.g. `emoji-icon-font`) if the text font lacks it,
+    /// and is sized and aligned to the same text line as the text.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn label_with_icon(&mut self, icon: char, text: impl ToString) -> Response {
+        self.label(format!("{} {}", icon, text.to_string()))
+    }
+
+    /// Show text on a single line, cut short with `…` if it is wider than `max_width`.
+    ///
+    /// The text is cut after the last whole word that fits,
+    /// or after the last char that fits if not even the first word does.
+    /// When the text is cut short, the full text is shown when hovering the label.
+    /// If not even the `…` fits, nothing is shown.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.label_truncated("a_very_long_file_name_that_does_not_fit.txt", 120.0);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn label_truncated(&mut self, text: impl ToString, max_width: f32) -> Response {
+        let label = Label::new(text).wrap(false);
+        let text_style = label.text_style_or_default(self.style());
+        match widgets::truncate_text(self.fonts(), text_style, label.text(), max_width) {
+            None => self.add(label),
+            Some(truncated) => {
+                let full_text = label.text().to_owned();
+                self.add(Label::new(truncated).wrap(false))
+                    .on_hover_text(full_text)
+            }
+        }
+    }
+
     /// Shortcut for `add(Label::new(text).heading())`
     pub fn heading(&mut self, label: impl Into<Label>) -> Response {
         label.into().heading().ui(self)
@@ -971,6 +1064,67 @@ impl Ui {
         label.into().code().ui(self)
     }
 
+    /// Show multi-line text as monospace in a shaded frame, e.g. for code or logs.
+    ///
+    /// Whitespace is kept as-is, and long lines will scroll horizontally instead of wrapping.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.code_block("fn main() {\n    println!(\"Hello world!\");\n}");
+    /// ```
+    pub fn code_block(&mut self, text: impl ToString) -> Response {
+        let id_source = self.auto_id_with("code_block");
+        Frame::group(self.style())
+            .fill(self.visuals().code_bg_color)
+            .show(self, |ui| {
+                ScrollArea::horizontal()
+                    .id_source(id_source)
+                    .show(ui, |ui| ui.add(Label::new(text).monospace().wrap(false)));
+            })
+            .response
+    }
+
+    /// Show text that mixes different colors, styles and sizes, laid out as one paragraph.
+    ///
+    /// The text wraps at `job.wrap_width` or the available width, whichever is smaller.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::{text::{LayoutJob, TextFormat}, Color32, TextStyle};
+    /// let mut job = LayoutJob::default();
+    /// job.append("error: ", 0.0, TextFormat::simple(TextStyle::Monospace, Color32::RED));
+    /// job.append("file not found", 0.0, TextFormat::simple(TextStyle::Body, Color32::WHITE));
+    /// ui.rich_label(job);
+    /// ```
+    pub fn rich_label(&mut self, mut job: epaint::text::LayoutJob) -> Response {
+        job.wrap_width = job.wrap_width.min(self.available_width());
+        let galley = self.fonts().layout_job(job);
+        let (rect, response) = self.allocate_exact_size(galley.size(), Sense::hover());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, galley.text()));
+        let pos = match galley.job.halign {
+            Align::LEFT => rect.left_top(),
+            Align::Center => rect.center_top(),
+            Align::RIGHT => rect.right_top(),
+        };
+        self.painter().galley(pos, galley);
+        response
+    }
+
+    /// Show read-only text that the user can select with the mouse and copy.
+    ///
+    /// Useful for e.g. error messages and ids.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.selectable_text("Error 0x1f: connection refused");
+    /// ```
+    pub fn selectable_text(&mut self, mut text: &str) -> Response {
+        TextEdit::multiline(&mut text)
+            .frame(false)
+            .desired_rows(1)
+            .ui(self)
+    }
+
     /// Shortcut for `add(Label::new(text).small())`
     pub fn small(&mut self, label: impl Into<Label>) -> Response {
         label.into().small().ui(self)
@@ -1045,6 +1199,25 @@ impl Ui {
         Button::new(text).small().ui(self)
     }
 
+    /// A small square button showing a single icon, e.g. for toolbars.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.horizontal(|ui| {
+    ///     if ui.icon_button('🗑').on_hover_text("Delete").clicked() { /* … */ }
+    ///     if ui.icon_button('✏').on_hover_text("Edit").clicked() { /* … */ }
+    /// });
+    /// ```
+    #[must_use = "You should check if the user clicked this with `if ui.icon_button(…).clicked() { … } "]
+    pub fn icon_button(&mut self, icon: char) -> Response {
+        let size = self.fonts().row_height(TextStyle::Body);
+        let icon_width = self.fonts().glyph_width(TextStyle::Body, icon);
+        Button::new(icon)
+            .small()
+            .padding(vec2(((size - icon_width) / 2.0).at_least(0.0), 0.0))
+            .ui(self)
+    }
+
     /// Show a checkbox.
     pub fn checkbox(&mut self, checked: &mut bool, text: impl ToString) -> Response {
         Checkbox::new(checked, text).ui(self)
@@ -1122,6 +1295,13 @@ impl Ui {
         Separator::default().ui(self)
     }
 
+    /// Show a spinner to indicate that something is loading.
+    ///
+    /// Shortcut for `add(Spinner::new())` (see [`Spinner`]).
+    pub fn spinner(&mut self) -> Response {
+        Spinner::new().ui(self)
+    }
+
     /// Modify an angle. The given angle should be in radians, but is shown to the user in degrees.
     /// The angle is NOT wrapped, so the user may select, for instance 720° = 2𝞃 = 4π
     pub fn drag_angle(&mut self, radians: &mut f32) -> Response {
@@ -1167,6 +1347,18 @@ impl Ui {
     pub fn image(&mut self, texture_id: TextureId, size: impl Into<Vec2>) -> Response {
         Image::new(texture_id, size).ui(self)
     }
+
+    /// A clickable image with a frame, e.g. for a toolbar.
+    ///
+    /// Usage: `if ui.image_button(texture_id, [16.0, 16.0]).clicked() { … }`
+    ///
+    /// Shortcut for `add(ImageButton::new(texture_id, size))`.
+    /// See [`ImageButton`] for more options, such as tinting and turning off the frame.
+    #[must_use = "You should check if the user clicked this with `if ui.image_button(…).clicked() { … } "]
+    #[inline(always)]
+    pub fn image_button(&mut self, texture_id: TextureId, size: impl Into<Vec2>) -> Response {
+        ImageButton::new(texture_id, size).ui(self)
+    }
 }
 
 /// # Colors
@@ -1281,6 +1473,8 @@ impl Ui {
     ///     // …
     /// });
     /// ```
+    ///
+    /// The child [`Ui`] shares the [`Id`] namespace of its parent; use [`Self::push_id`] for a separate one.
     pub fn scope<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
         self.scope_dyn(Box::new(add_contents))
     }
@@ -1298,6 +1492,57 @@ impl Ui {
         InnerResponse::new(ret, response)
     }
 
+    /// Like [`Self::scope`], but the child [`Ui`] gets an [`Id`] derived from `id_source`.
+    ///
+    /// Use this to avoid [`Id`] clashes, e.g. when showing two things with the same name:
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// for (i, name) in ["Same name", "Same name"].iter().enumerate() {
+    ///     ui.push_id(i, |ui| ui.collapsing(*name, |ui| ui.label("Contents")));
+    /// }
+    /// ```
+    pub fn push_id<R>(
+        &mut self,
+        id_source: impl Hash,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let child_rect = self.available_rect_before_wrap();
+        let next_auto_id_source = self.next_auto_id_source;
+        let mut child_ui = self.child_ui_with_id_source(child_rect, *self.layout(), id_source);
+        self.next_auto_id_source = next_auto_id_source; // Same as in `scope`.
+        let ret = add_contents(&mut child_ui);
+        let response = self.allocate_rect(child_ui.min_rect(), Sense::hover());
+        InnerResponse::new(ret, response)
+    }
+
+    /// Paint everything added in `add_contents` with the given opacity,
+    /// where `0.0` is fully transparent and `1.0` leaves it unchanged.
+    ///
+    /// Useful for fading things in and out, e.g. with [`crate::Context::animate_bool`].
+    /// Only affects shapes in the paint layer of this `Ui`, so not e.g. tooltips or popups.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let show_details = true;
+    /// let opacity = ui.ctx().animate_bool(egui::Id::new("details"), show_details);
+    /// ui.with_opacity(opacity, |ui| {
+    ///     ui.label("Fading details");
+    /// });
+    /// ```
+    pub fn with_opacity<R>(
+        &mut self,
+        opacity: f32,
+        add_contents: impl FnOnce(&mut Self) -> R,
+    ) -> InnerResponse<R> {
+        let start = self.painter.next_shape_idx();
+        let inner_response = self.scope(add_contents);
+        if opacity < 1.0 {
+            self.painter.multiply_opacity_from(start, opacity);
+        }
+        inner_response
+    }
+
     /// Redirect shapes to another paint layer.
     pub fn with_layer_id<R>(
         &mut self,
@@ -1321,6 +1566,9 @@ impl Ui {
 
     /// Create a child ui which is indented to the right.
     ///
+    /// The indentation ([`crate::style::Spacing::indent`]) is measured from the left edge of this [`Ui`],
+    /// so nested indents add up the same way inside e.g. [`Self::columns`].
+    ///
     /// The `id_source` here be anything at all.
     // TODO: remove `id_source` argument?
     #[inline(always)]
@@ -1421,6 +1669,143 @@ impl Ui {
         self.allocate_ui_with_layout_dyn(initial_size, layout, Box::new(add_contents))
     }
 
+    /// Like [`Self::horizontal`], but elements are centered on the Y axis
+    /// of the full row height, even when a tall widget comes after short ones.
+    ///
+    /// The height of the row is remembered from the previous frame,
+    /// so the first frame a row grows or shrinks it will be centered a bit off, and a repaint is requested.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.horizontal_centered(|ui|{
+    ///     ui.label("Centered next to");
+    ///     ui.add_sized([100.0, 100.0], egui::Button::new("a big button"));
+    /// });
+    /// ```
+    pub fn horizontal_centered<R>(
+        &mut self,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.horizontal_with_prev_height_dyn(Align::Center, Box::new(add_contents))
+    }
+
+    /// Like [`Self::horizontal`], but text of different sizes will share the same baseline,
+    /// e.g. a `heading` next to a normal `label`.
+    ///
+    /// Each [`Label`] is moved down by how much smaller the `ascent()` of its font is than that of
+    /// the tallest text in the row. Other widgets are aligned to the top of the row.
+    ///
+    /// The ascent of the row is remembered from the previous frame,
+    /// so the first frame the text is not aligned, and a repaint is requested.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.horizontal_baseline(|ui|{
+    ///     ui.label("Some body text next to");
+    ///     ui.heading("a heading");
+    /// });
+    /// ```
+    pub fn horizontal_baseline<R>(
+        &mut self,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.horizontal_baseline_dyn(Box::new(add_contents))
+    }
+
+    fn horizontal_baseline_dyn<'c, R>(
+        &mut self,
+        add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+    ) -> InnerResponse<R> {
+        #[derive(Clone, Copy, PartialEq)]
+        struct RowAscent(f32);
+
+        let id = self
+            .id
+            .with(("horizontal_baseline", self.next_auto_id_source));
+        let prev_ascent = self
+            .memory()
+            .id_data_temp
+            .get::<RowAscent>(&id)
+            .map_or(0.0, |ascent| ascent.0);
+
+        let initial_size = self.available_size_before_wrap();
+        let layout = if self.placer.prefer_right_to_left() {
+            Layout::right_to_left()
+        } else {
+            Layout::left_to_right()
+        }
+        .with_cross_align(Align::Min);
+        let InnerResponse { inner, response } = self.allocate_ui_with_layout_dyn(
+            initial_size,
+            layout,
+            Box::new(|ui| {
+                ui.placer.set_row_ascent(prev_ascent);
+                let ret = add_contents(ui);
+                (ret, ui.placer.max_text_ascent())
+            }),
+        );
+        let (ret, ascent) = inner;
+
+        let ascent = RowAscent(ascent);
+        if ascent != RowAscent(prev_ascent) {
+            self.memory().id_data_temp.insert(id, ascent);
+            self.ctx().request_repaint();
+        }
+        InnerResponse::new(ret, response)
+    }
+
+    /// How far down to move text with the given ascent so that it shares the baseline of
+    /// a [`Self::horizontal_baseline`] row. Zero in all other `Ui`:s.
+    pub(crate) fn baseline_padding(&mut self, ascent: f32) -> f32 {
+        self.placer.baseline_padding(ascent)
+    }
+
+    /// A horizontal layout with the given vertical alignment within the row height of last frame.
+    fn horizontal_with_prev_height_dyn<'c, R>(
+        &mut self,
+        cross_align: Align,
+        add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+    ) -> InnerResponse<R> {
+        #[derive(Clone, Copy, PartialEq)]
+        struct RowHeight(f32);
+
+        let id = self.id.with(("horizontal_row", self.next_auto_id_source));
+        let prev_height = self
+            .memory()
+            .id_data_temp
+            .get::<RowHeight>(&id)
+            .map_or(0.0, |height| height.0);
+
+        let initial_size = vec2(
+            self.available_size_before_wrap().x,
+            self.spacing().interact_size.y.max(prev_height),
+        );
+        let layout = if self.placer.prefer_right_to_left() {
+            Layout::right_to_left()
+        } else {
+            Layout::left_to_right()
+        }
+        .with_cross_align(cross_align);
+        let InnerResponse { inner, response } = self.allocate_ui_with_layout_dyn(
+            initial_size,
+            layout,
+            Box::new(|ui| {
+                let ret = add_contents(ui);
+                (ret, ui.placer.widget_bounds())
+            }),
+        );
+        let (ret, widget_bounds) = inner;
+
+        // Remember the height of the contents rather than of the row,
+        // which is at least `prev_height` and so could never shrink:
+        let height = RowHeight(widget_bounds.height().at_least(0.0));
+        if height != RowHeight(prev_height) {
+            self.memory().id_data_temp.insert(id, height);
+            self.ctx().request_repaint();
+        }
+        InnerResponse::new(ret, response)
+    }
+
     /// Start a ui with horizontal layout that wraps to a new row
     /// when it reaches the right edge of the `max_size`.
     /// After you have called this, the function registers the contents as any other widget.
@@ -1518,6 +1903,39 @@ impl Ui {
         )
     }
 
+    /// Add contents in a column of the given width, horizontally centered in the available space.
+    ///
+    /// Useful for e.g. a form or text that should not span the whole width of a wide window.
+    /// If there is less space than `width`, the column is made narrower.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.centered_column(300.0, |ui| {
+    ///     ui.heading("Sign in");
+    ///     ui.label("Some text that wraps at the width of the column.");
+    /// });
+    /// ```
+    pub fn centered_column<R>(
+        &mut self,
+        width: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let available_rect = self.available_rect_before_wrap();
+        let width = width.at_most(available_rect.width());
+        let mut column_rect = available_rect;
+        column_rect.min.x = available_rect.center().x - 0.5 * width;
+        column_rect.max.x = column_rect.min.x + width;
+
+        let mut child_ui = self.child_ui(column_rect, *self.layout());
+        let inner = add_contents(&mut child_ui);
+        let rect = child_ui.min_rect();
+        let item_spacing = self.spacing().item_spacing;
+        self.placer.advance_after_rects(rect, rect, item_spacing);
+        self.debug_show_rect(rect, Color32::GREEN);
+
+        InnerResponse::new(inner, self.interact(rect, child_ui.id, Sense::hover()))
+    }
+
     /// The new layout will take up all available space.
     ///
     /// ```
@@ -1549,6 +1967,7 @@ impl Ui {
         let rect = child_ui.min_rect();
         let item_spacing = self.spacing().item_spacing;
         self.placer.advance_after_rects(rect, rect, item_spacing);
+        self.debug_show_rect(rect, Color32::GREEN);
 
         if self.style().debug.debug_on_hover && self.rect_contains_pointer(rect) {
             let painter = self.ctx().debug_painter();
@@ -1588,6 +2007,16 @@ impl Ui {
 
     /// Move to the next row in a grid layout or wrapping layout.
     /// Otherwise does nothing.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.horizontal_wrapped(|ui| {
+    ///     let first = ui.label("First row").rect;
+    ///     ui.end_row();
+    ///     let second = ui.label("Second row").rect;
+    ///     assert!(first.bottom() <= second.top());
+    /// });
+    /// ```
     pub fn end_row(&mut self) {
         self.placer
             .end_row(self.spacing().item_spacing, &self.painter().clone());
@@ -1598,6 +2027,24 @@ impl Ui {
         self.placer.set_row_height(height);
     }
 
+    /// Split the available space into a left and right pane, with a draggable divider in between.
+    ///
+    /// Shortcut for `Split::horizontal(id_source).show(ui, add_left, add_right)`.
+    /// See [`Split`] for more options.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.split_horizontal("my_split", |ui| ui.label("Left"), |ui| ui.label("Right"));
+    /// ```
+    pub fn split_horizontal<R1, R2>(
+        &mut self,
+        id_source: impl Hash,
+        add_left: impl FnOnce(&mut Ui) -> R1,
+        add_right: impl FnOnce(&mut Ui) -> R2,
+    ) -> (R1, R2) {
+        Split::horizontal(id_source).show(self, add_left, add_right)
+    }
+
     /// Temporarily split split an Ui into several columns.
     ///
     /// ```
@@ -1613,14 +2060,93 @@ impl Ui {
         num_columns: usize,
         add_contents: impl FnOnce(&mut [Self]) -> R,
     ) -> R {
+        self.columns_dyn(num_columns, Box::new(add_contents)).inner
+    }
+
+    /// Like [`Self::columns`], but also returns a [`Response`] covering all the columns.
+    ///
+    /// ```
+    /// # let mut ui = egui::Ui::__test();
+    /// let response = ui.columns_with_response(2, |columns| {
+    ///     columns[0].label("First column");
+    ///     columns[1].label("Second column");
+    /// }).response;
+    /// let size_used = response.rect.size();
+    /// ```
+    #[inline]
+    pub fn columns_with_response<R>(
+        &mut self,
+        num_columns: usize,
+        add_contents: impl FnOnce(&mut [Self]) -> R,
+    ) -> InnerResponse<R> {
         self.columns_dyn(num_columns, Box::new(add_contents))
     }
 
+    /// Like [`Self::columns`], but with a [`Frame`] around each column.
+    ///
+    /// All the frames are made as tall as the tallest column, so their backgrounds line up.
+    ///
+    /// ```
+    /// # let mut ui = egui::Ui::__test();
+    /// let (name, notes) = ui
+    ///     .framed_columns(2, egui::Frame::group(ui.style()), |columns| {
+    ///         let name = columns[0].button("Name");
+    ///         columns[1].label("Some notes");
+    ///         let notes = columns[1].button("Notes");
+    ///         (name, notes)
+    ///     })
+    ///     .inner;
+    /// ```
+    pub fn framed_columns<R>(
+        &mut self,
+        num_columns: usize,
+        frame: Frame,
+        add_contents: impl FnOnce(&mut [Self]) -> R,
+    ) -> InnerResponse<R> {
+        self.columns_dyn(
+            num_columns,
+            Box::new(|columns: &mut [Self]| {
+                let backgrounds: Vec<_> = columns
+                    .iter()
+                    .map(|column| column.painter().add(Shape::Noop))
+                    .collect();
+                let mut contents: Vec<Self> = columns
+                    .iter_mut()
+                    .map(|column| {
+                        let inner_rect = column.available_rect_before_wrap().shrink2(frame.margin);
+                        let inner_rect =
+                            Rect::from_min_max(inner_rect.min, inner_rect.max.max(inner_rect.min));
+                        column.child_ui(inner_rect, *column.layout())
+                    })
+                    .collect();
+
+                let ret = add_contents(&mut contents[..]);
+
+                let max_height = contents
+                    .iter()
+                    .map(|content| content.min_rect().height())
+                    .fold(0.0, f32::max);
+                for ((column, content), background) in
+                    columns.iter_mut().zip(&contents).zip(backgrounds)
+                {
+                    let mut outer_rect = content.min_rect().expand2(frame.margin);
+                    outer_rect.min.x = outer_rect.min.x.min(column.max_rect().left());
+                    outer_rect.max.x = outer_rect.max.x.max(column.max_rect().right());
+                    outer_rect.max.y = outer_rect.min.y + max_height + 2.0 * frame.margin.y;
+                    let shape = frame.paint(column.painter().round_rect_to_pixels(outer_rect));
+                    column.painter().set(background, shape);
+                    column.allocate_rect(outer_rect, Sense::hover());
+                }
+                ret
+            }),
+        )
+    }
+
     fn columns_dyn<'c, R>(
         &mut self,
         num_columns: usize,
         add_contents: Box<dyn FnOnce(&mut [Self]) -> R + 'c>,
-    ) -> R {
+    ) -> InnerResponse<R> {
         // TODO: ensure there is space
         let spacing = self.spacing().item_spacing.x;
         let total_spacing = spacing * (num_columns as f32 - 1.0);
@@ -1654,8 +2180,50 @@ impl Ui {
         let total_required_width = total_spacing + max_column_width * (num_columns as f32);
 
         let size = vec2(self.available_width().max(total_required_width), max_height);
-        self.advance_cursor_after_rect(Rect::from_min_size(top_left, size));
-        result
+        let rect = Rect::from_min_size(top_left, size);
+        let id = self.advance_cursor_after_rect(rect);
+        let response = self.interact(rect, id, Sense::hover());
+        InnerResponse::new(result, response)
+    }
+
+    /// Create a child `Ui` that can be built independently of this one, e.g. on another thread.
+    ///
+    /// The returned `Ui` paints into its own list of shapes,
+    /// which will not be shown until you give the `Ui` back to [`Self::merge`].
+    /// Each call gives a new, unique `Id`, so the children can be built in any order.
+    ///
+    /// ```
+    /// # let mut ui = egui::Ui::__test();
+    /// let rect = ui.available_rect_before_wrap();
+    /// let left = egui::Rect::from_min_max(rect.min, rect.center_bottom());
+    /// let right = egui::Rect::from_min_max(rect.center_top(), rect.max);
+    /// let mut left = ui.split_off(left, egui::Layout::top_down(egui::Align::Min));
+    /// let right = ui.split_off(right, egui::Layout::top_down(egui::Align::Min));
+    ///
+    /// let right = std::thread::spawn(move || {
+    ///     let mut right = right;
+    ///     right.label("Built on another thread");
+    ///     right
+    /// });
+    /// left.label("Built on this thread");
+    ///
+    /// // Painted in the given order, regardless of when each child was built:
+    /// ui.merge(vec![left, right.join().unwrap()]);
+    /// ```
+    pub fn split_off(&mut self, max_rect: Rect, layout: Layout) -> Self {
+        let id_source = ("split_off", self.next_auto_id_source);
+        let mut child_ui = self.child_ui_with_id_source(max_rect, layout, id_source);
+        child_ui.painter = child_ui.painter.detached();
+        child_ui
+    }
+
+    /// Paint the contents of the given children (from [`Self::split_off`]) in the order given,
+    /// and expand this `Ui` to contain them.
+    pub fn merge(&mut self, children: impl IntoIterator<Item = Self>) {
+        for child in children {
+            self.painter.append(&child.painter);
+            self.expand_to_include_rect(child.min_rect());
+        }
     }
 }
 
@@ -1663,6 +2231,15 @@ impl Ui {
 
 /// # Debug stuff
 impl Ui {
+    /// Outline the given rectangle if [`crate::style::DebugOptions::show_widgets`] is on.
+    fn debug_show_rect(&self, rect: Rect, color: Color32) {
+        if self.style().debug.show_widgets {
+            self.ctx()
+                .debug_painter()
+                .rect_stroke(rect, 0.0, (1.0, color));
+        }
+    }
+
     /// Shows where the next widget is going to be placed
     pub fn debug_paint_cursor(&self) {
         self.placer.debug_paint_cursor(&self.painter, "next");
@@ -1678,3 +2255,205 @@ impl Ui {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn indent_is_relative_to_the_left_edge() {
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(Default::default());
+    CentralPanel::default().show(&ctx, |ui| {
+        let indent = ui.spacing().indent;
+        ui.columns(2, |columns| {
+            let column_left = columns[1].max_rect().left();
+            columns[1].label("Some text to move the cursor");
+            columns[1].indent("outer", |ui| {
+                assert_eq!(ui.max_rect().left(), column_left + indent);
+                ui.label("Indented");
+                ui.indent("inner", |ui| {
+                    assert_eq!(ui.max_rect().left(), column_left + 2.0 * indent);
+                });
+            });
+        });
+    });
+    let _ = ctx.end_frame();
+}
+
+#[cfg(test)]
+#[test]
+fn centered_column_is_centered() {
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+        ..Default::default()
+    });
+    CentralPanel::default().show(&ctx, |ui| {
+        let available_rect = ui.available_rect_before_wrap();
+        let inner = ui.centered_column(200.0, |ui| {
+            ui.label("Centered");
+            ui.max_rect()
+        });
+        let column_rect = inner.inner;
+        assert_eq!(column_rect.width(), 200.0);
+        assert_eq!(column_rect.center().x, available_rect.center().x);
+        assert!(inner.response.rect.height() > 0.0);
+        assert!(
+            ui.cursor().top() > available_rect.top(),
+            "the space is used"
+        );
+    });
+    let _ = ctx.end_frame();
+}
+
+#[cfg(test)]
+#[test]
+fn centered_column_in_indented_ui() {
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+        ..Default::default()
+    });
+    SidePanel::left("side").show(&ctx, |ui| ui.label("Side panel"));
+    CentralPanel::default().show(&ctx, |ui| {
+        ui.indent("indent", |ui| {
+            let available_rect = ui.available_rect_before_wrap();
+            assert!(available_rect.left() > 100.0, "not at the screen origin");
+            let column_rect = ui.centered_column(200.0, |ui| ui.max_rect()).inner;
+            assert_eq!(column_rect.center().x, available_rect.center().x);
+        });
+    });
+    let _ = ctx.end_frame();
+}
+
+#[cfg(test)]
+#[test]
+fn scoped_style_is_inherited_but_does_not_leak() {
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(Default::default());
+    CentralPanel::default().show(&ctx, |ui| {
+        let default_indent = ui.spacing().indent;
+        ui.scope(|ui| {
+            ui.spacing_mut().indent = 2.0 * default_indent;
+            ui.vertical(|ui| {
+                assert_eq!(ui.spacing().indent, 2.0 * default_indent, "inherited");
+            });
+        });
+        assert_eq!(ui.spacing().indent, default_indent, "not leaked");
+        assert_eq!(ctx.style().spacing.indent, default_indent, "not global");
+    });
+    let _ = ctx.end_frame();
+}
+
+#[cfg(test)]
+#[test]
+fn scope_and_push_id_reserve_only_their_contents() {
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(Default::default());
+    CentralPanel::default().show(&ctx, |ui| {
+        let direct = ui.label("Component").rect;
+        let scoped = ui.scope(|ui| ui.label("Component").rect);
+        assert_eq!(scoped.response.rect, scoped.inner);
+        assert_eq!(scoped.inner.left(), direct.left());
+
+        for i in 0..2 {
+            let before = ui.cursor();
+            let inner = ui.push_id(i, |ui| ui.collapsing("Component", |ui| ui.label("Body")));
+            assert_eq!(inner.response.rect.min, before.min);
+            assert_eq!(inner.response.rect, inner.inner.header_response.rect);
+        }
+    });
+    let _ = ctx.end_frame();
+    assert!(ctx.id_clashes().is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn framed_columns_have_equal_heights() {
+    let fill = Color32::from_rgb(1, 2, 3);
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(Default::default());
+    CentralPanel::default().show(&ctx, |ui| {
+        let frame = Frame::none().fill(fill).margin((4.0, 4.0));
+        ui.framed_columns(2, frame, |columns| {
+            columns[0].label("Short");
+            for _ in 0..3 {
+                columns[1].label("Tall");
+            }
+        });
+    });
+    let (_, shapes) = ctx.end_frame();
+    let backgrounds: Vec<Rect> = shapes
+        .iter()
+        .filter_map(|clipped| match clipped.1 {
+            Shape::Rect { rect, fill: f, .. } if f == fill => Some(rect),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(backgrounds.len(), 2);
+    assert_eq!(backgrounds[0].top(), backgrounds[1].top());
+    assert_eq!(backgrounds[0].height(), backgrounds[1].height());
+    assert!(backgrounds[0].right() < backgrounds[1].left());
+}
+
+#[cfg(test)]
+#[test]
+fn centered_row_shrinks_when_tall_contents_are_removed() {
+    let mut ctx = CtxRef::default();
+    let mut row_heights = vec![];
+    for frame in 0..4 {
+        ctx.begin_frame(Default::default());
+        CentralPanel::default().show(&ctx, |ui| {
+            let response = ui
+                .horizontal_centered(|ui| {
+                    ui.label("Label");
+                    if frame < 2 {
+                        ui.add_sized([100.0, 100.0], Button::new("Tall"));
+                    }
+                })
+                .response;
+            row_heights.push(response.rect.height());
+        });
+        let _ = ctx.end_frame();
+    }
+    let interact_height = ctx.style().spacing.interact_size.y;
+    assert_eq!(row_heights, [100.0, 100.0, 100.0, interact_height]);
+}
+
+#[cfg(test)]
+#[test]
+fn horizontal_baseline_aligns_text_of_different_sizes() {
+    let mut ctx = CtxRef::default();
+    let mut baselines = vec![];
+    for _ in 0..2 {
+        ctx.begin_frame(Default::default());
+        CentralPanel::default().show(&ctx, |ui| {
+            let fonts = ui.fonts();
+            let body_ascent = fonts[TextStyle::Body].ascent();
+            let heading_ascent = fonts[TextStyle::Heading].ascent();
+            assert!(body_ascent < heading_ascent);
+            baselines = ui
+                .horizontal_baseline(|ui| {
+                    let body = ui.label("Body").rect;
+                    let heading = ui.heading("Heading").rect;
+                    vec![body.top() + body_ascent, heading.top() + heading_ascent]
+                })
+                .inner;
+        });
+        let _ = ctx.end_frame();
+    }
+    assert_eq!(baselines[0], baselines[1]);
+}
+
+#[cfg(test)]
+#[test]
+fn label_truncated_fits_with_overridden_text_style() {
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(Default::default());
+    CentralPanel::default().show(&ctx, |ui| {
+        ui.style_mut().override_text_style = Some(TextStyle::Heading);
+        let rect = ui
+            .label_truncated("The quick brown fox jumps over the lazy dog", 100.0)
+            .rect;
+        assert!(rect.width() <= 100.0, "{} is too wide", rect.width());
+    });
+    let _ = ctx.end_frame();
+}
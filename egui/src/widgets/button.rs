@@ -33,6 +33,7 @@ pub struct Button {
     frame: Option<bool>,
     wrap: Option<bool>,
     min_size: Vec2,
+    padding: Option<Vec2>,
 }
 
 impl Button {
@@ -49,9 +50,12 @@ impl Button {
             frame: None,
             wrap: None,
             min_size: Vec2::ZERO,
+            padding: None,
         }
     }
 
+    /// Override the text color, which otherwise comes from [`crate::style::Visuals::override_text_color`]
+    /// or the [`crate::style::WidgetVisuals`] of the current interaction state.
     pub fn text_color(mut self, text_color: Color32) -> Self {
         self.text_color = Some(text_color);
         self
@@ -62,6 +66,7 @@ impl Button {
         self
     }
 
+    /// Override the text style, which otherwise is [`Style::override_text_style`] or [`TextStyle::Button`].
     pub fn text_style(mut self, text_style: TextStyle) -> Self {
         self.text_style = Some(text_style);
         self
@@ -125,7 +130,28 @@ impl Button {
         self
     }
 
-    pub(crate) fn min_size(mut self, min_size: Vec2) -> Self {
+    /// Space between the text and the edge of the button,
+    /// instead of [`crate::style::Spacing::button_padding`].
+    ///
+    /// Use a small padding to fit many buttons in a tight toolbar.
+    pub fn padding(mut self, padding: impl Into<Vec2>) -> Self {
+        self.padding = Some(padding.into());
+        self
+    }
+
+    /// Make the button at least this large, e.g. to give a row of buttons the same width.
+    ///
+    /// By default the button is at least [`crate::style::Spacing::interact_size`] high.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let can_save = true;
+    /// let button = egui::Button::new("Save")
+    ///     .min_size(egui::vec2(80.0, 0.0))
+    ///     .enabled(can_save);
+    /// assert!(ui.add(button).rect.width() >= 80.0);
+    /// ```
+    pub fn min_size(mut self, min_size: Vec2) -> Self {
         self.min_size = min_size;
         self
     }
@@ -144,6 +170,7 @@ impl Button {
             frame,
             wrap,
             min_size,
+            padding,
         } = self;
 
         let frame = frame.unwrap_or_else(|| ui.visuals().button_frame);
@@ -152,10 +179,13 @@ impl Button {
             .or(ui.style().override_text_style)
             .unwrap_or(TextStyle::Button);
 
-        let mut button_padding = ui.spacing().button_padding;
-        if small {
-            button_padding.y = 0.0;
-        }
+        let button_padding = padding.unwrap_or_else(|| {
+            let mut button_padding = ui.spacing().button_padding;
+            if small {
+                button_padding.y = 0.0;
+            }
+            button_padding
+        });
         let total_extra = button_padding + button_padding;
 
         let wrap = wrap.unwrap_or_else(|| ui.wrap_text());
@@ -551,3 +581,36 @@ impl Widget for ImageButton {
         response
     }
 }
+
+#[cfg(test)]
+#[test]
+fn checkbox_is_changed_only_when_clicked() {
+    let mut ctx = CtxRef::default();
+    let mut checked = false;
+    let mut checkbox_pos = Pos2::ZERO;
+    let mut changed = vec![];
+    for frame in 0..5 {
+        let events = match frame {
+            1 => vec![Event::PointerMoved(checkbox_pos)],
+            2 | 3 => vec![Event::PointerButton {
+                pos: checkbox_pos,
+                button: PointerButton::Primary,
+                pressed: frame == 2,
+                modifiers: Default::default(),
+            }],
+            _ => vec![],
+        };
+        ctx.begin_frame(RawInput {
+            events,
+            ..Default::default()
+        });
+        CentralPanel::default().show(&ctx, |ui| {
+            let response = ui.checkbox(&mut checked, "Check me");
+            checkbox_pos = response.rect.center();
+            changed.push(response.changed());
+        });
+        let _ = ctx.end_frame();
+    }
+    assert_eq!(changed, [false, false, false, true, false]);
+    assert!(checked);
+}
@@ -360,9 +360,7 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
             })
             .response;
 
-        if !button_response.clicked()
-            && (ui.input().key_pressed(Key::Escape) || area_response.clicked_elsewhere())
-        {
+        if !button_response.clicked() && area_response.clicked_elsewhere() {
             ui.memory().close_popup();
         }
     }
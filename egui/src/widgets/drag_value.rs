@@ -55,8 +55,11 @@ pub struct DragValue<'a> {
     clamp_range: RangeInclusive<f64>,
     min_decimals: usize,
     max_decimals: Option<usize>,
+    custom_formatter: Option<CustomFormatter<'a>>,
 }
 
+pub(crate) type CustomFormatter<'a> = Box<dyn 'a + Fn(f64) -> String>;
+
 impl<'a> DragValue<'a> {
     pub fn new<Num: emath::Numeric>(value: &'a mut Num) -> Self {
         let slf = Self::from_get_set(move |v: Option<f64>| {
@@ -84,6 +87,7 @@ impl<'a> DragValue<'a> {
             clamp_range: f64::NEG_INFINITY..=f64::INFINITY,
             min_decimals: 0,
             max_decimals: None,
+            custom_formatter: None,
         }
     }
 
@@ -135,6 +139,21 @@ impl<'a> DragValue<'a> {
         self
     }
 
+    /// Show the value formatted by this function instead of as a plain number, e.g. as a percentage.
+    ///
+    /// The [`Self::prefix`] and [`Self::suffix`] are still added.
+    /// When the user clicks to type in a value, it is shown and parsed as a plain number.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut opacity: f32 = 0.5;
+    /// ui.add(egui::DragValue::new(&mut opacity).speed(0.01).custom_formatter(|v| format!("{:.0}%", 100.0 * v)));
+    /// ```
+    pub fn custom_formatter(mut self, formatter: impl 'a + Fn(f64) -> String) -> Self {
+        self.custom_formatter = Some(Box::new(formatter));
+        self
+    }
+
     /// Set an exact number of decimals to display.
     /// Values will also be rounded to this number of decimals.
     /// Normally you don't need to pick a precision, as the slider will intelligently pick a precision for you.
@@ -156,6 +175,7 @@ impl<'a> Widget for DragValue<'a> {
             suffix,
             min_decimals,
             max_decimals,
+            custom_formatter,
         } = self;
 
         let is_slow_speed =
@@ -208,6 +228,7 @@ impl<'a> Widget for DragValue<'a> {
             }
             response
         } else {
+            let value_text = custom_formatter.as_ref().map_or(value_text, |f| f(value));
             let button = Button::new(format!("{}{}{}", prefix, value_text, suffix))
                 .sense(Sense::click_and_drag())
                 .text_style(TextStyle::Monospace)
@@ -275,6 +296,15 @@ impl<'a> Widget for DragValue<'a> {
                     let new_value = clamp_to_range(new_value, clamp_range);
                     set(&mut get_set_value, new_value);
                 }
+            } else {
+                let scroll_delta = response.take_value_scroll_delta();
+                let delta_points = scroll_delta.x + scroll_delta.y; // Increase when scrolling up
+                if delta_points != 0.0 {
+                    let new_value = value + speed * delta_points as f64;
+                    let new_value = emath::round_to_decimals(new_value, auto_decimals);
+                    let new_value = clamp_to_range(new_value, clamp_range);
+                    set(&mut get_set_value, new_value);
+                }
             }
 
             response
@@ -293,3 +323,55 @@ fn clamp_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
         range.start().max(*range.end()),
     )
 }
+
+#[cfg(test)]
+#[test]
+fn scroll_over_drag_value() {
+    let mut ctx = CtxRef::default();
+    let mut style = (*ctx.style()).clone();
+    style.interaction.scroll_changes_values = true;
+    ctx.set_style(style);
+
+    let mut value = 0.0;
+    let mut rect = Rect::NOTHING;
+    for frame in 0..3 {
+        ctx.begin_frame(RawInput {
+            events: vec![Event::PointerMoved(rect.center())],
+            scroll_delta: if frame == 2 {
+                vec2(0.0, 3.0)
+            } else {
+                Vec2::ZERO
+            },
+            ..Default::default()
+        });
+        CentralPanel::default().show(&ctx, |ui| {
+            rect = ui.add(DragValue::new(&mut value).speed(0.5)).rect;
+        });
+        let _ = ctx.end_frame();
+    }
+    assert_eq!(value, 1.5);
+}
+
+#[cfg(test)]
+#[test]
+fn drag_value_custom_formatter() {
+    let mut ctx = CtxRef::default();
+    ctx.begin_frame(Default::default());
+    let mut value = 0.5;
+    CentralPanel::default().show(&ctx, |ui| {
+        ui.add(
+            DragValue::new(&mut value)
+                .prefix("Opacity: ")
+                .custom_formatter(|v| format!("{:.0}%", 100.0 * v)),
+        );
+    });
+    let (_, shapes) = ctx.end_frame();
+    let texts: Vec<&str> = shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.1 {
+            Shape::Text(text) => Some(text.galley.text()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(texts, ["Opacity: 50%"]);
+}
@@ -53,7 +53,7 @@ impl Hyperlink {
 
 impl Widget for Hyperlink {
     fn ui(self, ui: &mut Ui) -> Response {
-        let Hyperlink { url, label } = self;
+        let Hyperlink { url, mut label } = self;
         let (pos, galley, response) = label.layout_in_ui(ui);
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Hyperlink, galley.text()));
 
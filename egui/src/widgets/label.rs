@@ -1,6 +1,6 @@
 use crate::*;
 use epaint::{
-    text::{LayoutJob, LayoutSection, TextFormat},
+    text::{Fonts, LayoutJob, LayoutSection, TextFormat},
     Galley,
 };
 use std::sync::Arc;
@@ -13,6 +13,19 @@ use std::sync::Arc;
 /// ui.add(egui::Label::new("Equivalent"));
 /// ui.add(egui::Label::new("With Options").text_color(egui::Color32::RED));
 /// ```
+///
+/// Wrapped text is aligned and justified according to the [`Layout`] of the [`Ui`]:
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let long_text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit.";
+/// use egui::{Align, Layout};
+/// ui.with_layout(Layout::top_down(Align::Center), |ui| ui.label(long_text));
+/// ui.with_layout(Layout::top_down(Align::RIGHT), |ui| ui.label(long_text));
+/// ui.with_layout(Layout::top_down(Align::LEFT).with_cross_justify(true), |ui| {
+///     ui.label(long_text) // spread out the words so that each wrapped row fills the width
+/// });
+/// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Label {
     // TODO: not pub
@@ -34,8 +47,13 @@ pub struct Label {
 impl Label {
     #[allow(clippy::needless_pass_by_value)]
     pub fn new(text: impl ToString) -> Self {
+        Self::from_string(text.to_string())
+    }
+
+    /// Like [`Self::new`], but reuses the given `String` instead of copying it.
+    fn from_string(text: String) -> Self {
         Self {
-            text: text.to_string(),
+            text,
             wrap: None,
             text_style: None,
             background_color: Color32::TRANSPARENT,
@@ -105,6 +123,16 @@ impl Label {
     }
 
     /// draw a line through the text, crossing it out
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut done = true;
+    /// ui.horizontal(|ui| {
+    ///     ui.checkbox(&mut done, "");
+    ///     let label = egui::Label::new("Buy milk");
+    ///     ui.add(if done { label.strikethrough().weak() } else { label });
+    /// });
+    /// ```
     pub fn strikethrough(mut self) -> Self {
         self.strikethrough = true;
         self
@@ -169,23 +197,29 @@ impl Label {
         self.layout_width(ui, max_width, line_color)
     }
 
+    /// Like [`Self::layout`], but moves the text into the layout job to avoid copying it.
+    /// The text of `self` is left empty.
+    fn layout_taking_text(&mut self, ui: &Ui) -> Arc<Galley> {
+        let max_width = ui.available_width();
+        let line_color = self.get_text_color(ui, ui.visuals().text_color());
+        let (halign, justify) = halign_and_justify(ui);
+        let text = std::mem::take(&mut self.text);
+        self.layout_impl(ui, text, 0.0, max_width, 0.0, line_color, halign, justify)
+    }
+
     /// `line_color`: used for underline and strikethrough, if any.
     pub fn layout_width(&self, ui: &Ui, max_width: f32, line_color: Color32) -> Arc<Galley> {
-        let (halign, justify) = if ui.is_grid() {
-            (Align::LEFT, false) // TODO: remove special Grid hacks like these
-        } else {
-            (
-                ui.layout().horizontal_align(),
-                ui.layout().horizontal_justify(),
-            )
-        };
-        self.layout_impl(ui, 0.0, max_width, 0.0, line_color, halign, justify)
+        let (halign, justify) = halign_and_justify(ui);
+        let text = self.text.clone();
+        self.layout_impl(ui, text, 0.0, max_width, 0.0, line_color, halign, justify)
     }
 
+    /// `text` is the text of the label, passed separately so that it can be moved in.
     #[allow(clippy::too_many_arguments)]
     fn layout_impl(
         &self,
         ui: &Ui,
+        text: String,
         leading_space: f32,
         max_width: f32,
         first_row_min_height: f32,
@@ -221,11 +255,12 @@ impl Label {
             ui.layout().vertical_align()
         };
 
+        let byte_range = 0..text.len();
         let job = LayoutJob {
-            text: self.text.clone(), // TODO: avoid clone
+            text,
             sections: vec![LayoutSection {
                 leading_space,
-                byte_range: 0..self.text.len(),
+                byte_range,
                 format: TextFormat {
                     style: text_style,
                     color: Color32::TEMPORARY_COLOR,
@@ -318,7 +353,9 @@ impl Label {
     }
 
     /// Do layout and place the galley in the ui, without painting it or adding widget info.
-    pub(crate) fn layout_in_ui(&self, ui: &mut Ui) -> (Pos2, Arc<Galley>, Response) {
+    ///
+    /// The text is moved into the returned `Galley`, so the text of `self` is left empty.
+    pub(crate) fn layout_in_ui(&mut self, ui: &mut Ui) -> (Pos2, Arc<Galley>, Response) {
         let sense = self.sense;
         let max_width = ui.available_width();
 
@@ -338,8 +375,10 @@ impl Label {
             let default_color = self.get_text_color(ui, ui.visuals().text_color());
             let halign = Align::Min;
             let justify = false;
+            let text = std::mem::take(&mut self.text);
             let galley = self.layout_impl(
                 ui,
+                text,
                 first_row_indentation,
                 max_width,
                 first_row_min_height,
@@ -359,8 +398,17 @@ impl Label {
             }
             (pos, galley, response)
         } else {
-            let galley = self.layout(ui);
-            let (rect, response) = ui.allocate_exact_size(galley.size(), sense);
+            let text_style = self.text_style_or_default(ui.style());
+            let galley = self.layout_taking_text(ui);
+            let padding = ui.baseline_padding(ui.fonts()[text_style].ascent());
+            let (rect, response) = if padding > 0.0 {
+                // Move down to the baseline of the row, see `Ui::horizontal_baseline`:
+                let (id, rect) = ui.allocate_space(galley.size() + vec2(0.0, padding));
+                let rect = Rect::from_min_size(rect.min + vec2(0.0, padding), galley.size());
+                (rect, ui.interact(rect, id, sense))
+            } else {
+                ui.allocate_exact_size(galley.size(), sense)
+            };
             let pos = match galley.job.halign {
                 Align::LEFT => rect.left_top(),
                 Align::Center => rect.center_top(),
@@ -372,7 +420,7 @@ impl Label {
 }
 
 impl Widget for Label {
-    fn ui(self, ui: &mut Ui) -> Response {
+    fn ui(mut self, ui: &mut Ui) -> Response {
         let (pos, galley, response) = self.layout_in_ui(ui);
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, galley.text()));
         let response_color = ui.style().interact(&response).text_color();
@@ -395,6 +443,142 @@ impl From<&String> for Label {
 
 impl From<String> for Label {
     fn from(s: String) -> Label {
-        Label::new(s)
+        Label::from_string(s)
+    }
+}
+
+/// How to align and justify text in this `Ui`.
+fn halign_and_justify(ui: &Ui) -> (Align, bool) {
+    if ui.is_grid() {
+        (Align::LEFT, false) // TODO: remove special Grid hacks like these
+    } else {
+        (
+            ui.layout().horizontal_align(),
+            ui.layout().horizontal_justify(),
+        )
+    }
+}
+
+/// Cut `text` short so that it fits on one line of `max_width`, ending it with `…`.
+///
+/// Cuts at the last word break that fits, or at any char if not even the first word fits.
+/// Returns `None` if the text already fits.
+/// Returns an empty string if not even the `…` fits.
+pub(crate) fn truncate_text(
+    fonts: &Fonts,
+    text_style: TextStyle,
+    text: &str,
+    max_width: f32,
+) -> Option<String> {
+    let galley = fonts.layout_no_wrap(text.to_owned(), text_style, Color32::TEMPORARY_COLOR);
+    if galley.rows.len() <= 1 && galley.size().x <= max_width {
+        return None;
+    }
+
+    let width_before_ellipsis = max_width - fonts.glyph_width(text_style, '…');
+    if width_before_ellipsis < 0.0 {
+        return Some(String::new());
+    }
+
+    // Only the first row is shown, and it has one glyph per char:
+    let num_chars = galley.rows[0]
+        .glyphs
+        .iter()
+        .take_while(|glyph| glyph.max_x() <= width_before_ellipsis)
+        .count();
+    let mut truncated: String = text.chars().take(num_chars).collect();
+    let cuts_a_word = text[truncated.len()..]
+        .chars()
+        .next()
+        .map_or(false, |next| !next.is_whitespace());
+    if cuts_a_word {
+        // Rather "quick…" than "quick br…", unless that leaves nothing:
+        let last_word_end = truncated
+            .trim_end_matches(|c: char| !c.is_whitespace())
+            .trim_end()
+            .len();
+        if last_word_end > 0 {
+            truncated.truncate(last_word_end);
+        }
+    }
+    truncated.truncate(truncated.trim_end().len()); // "foo…" rather than "foo …"
+    truncated.push('…');
+    Some(truncated)
+}
+
+#[cfg(test)]
+#[test]
+fn truncate_text_with_ellipsis() {
+    let fonts = Fonts::from_definitions(1.0, Default::default());
+    let style = TextStyle::Body;
+    let text = "The quick brown fox";
+    let full_width = fonts
+        .layout_no_wrap(text.into(), style, Color32::WHITE)
+        .size()
+        .x;
+
+    assert_eq!(truncate_text(&fonts, style, text, full_width), None);
+
+    let truncated = truncate_text(&fonts, style, text, 0.5 * full_width).unwrap();
+    assert!(truncated.ends_with('…'));
+    assert!(text.starts_with(truncated.trim_end_matches('…')));
+    let truncated_width = fonts
+        .layout_no_wrap(truncated, style, Color32::WHITE)
+        .size()
+        .x;
+    assert!(truncated_width <= 0.5 * full_width);
+
+    let width = |text: &str| {
+        fonts
+            .layout_no_wrap(text.into(), style, Color32::WHITE)
+            .size()
+            .x
+    };
+    let almost_two_words = width("The quick br…");
+    assert_eq!(
+        truncate_text(&fonts, style, text, almost_two_words).unwrap(),
+        "The quick…",
+        "cut at a word break"
+    );
+    let almost_one_word = width("The_quick_br…");
+    assert_eq!(
+        truncate_text(&fonts, style, "The_quick_brown_fox", almost_one_word).unwrap(),
+        "The_quick_br…",
+        "no word break to cut at"
+    );
+
+    assert_eq!(truncate_text(&fonts, style, text, 1.0).unwrap(), "");
+    assert!(truncate_text(&fonts, style, "two\nlines", 1000.0).is_some());
+}
+
+#[cfg(test)]
+#[test]
+fn label_with_click_sense_is_clicked() {
+    let mut ctx = CtxRef::default();
+    let mut label_pos = Pos2::ZERO;
+    let mut clicked = vec![];
+    for frame in 0..4 {
+        let events = match frame {
+            0 => vec![],
+            1 => vec![Event::PointerMoved(label_pos)],
+            _ => vec![Event::PointerButton {
+                pos: label_pos,
+                button: PointerButton::Primary,
+                pressed: frame == 2,
+                modifiers: Default::default(),
+            }],
+        };
+        ctx.begin_frame(RawInput {
+            events,
+            ..Default::default()
+        });
+        CentralPanel::default().show(&ctx, |ui| {
+            let response = ui.add(Label::new("click me").sense(Sense::click()));
+            label_pos = response.rect.center();
+            clicked.push(response.clicked());
+            assert!(!ui.label("not me").sense.click);
+        });
+        let _ = ctx.end_frame();
     }
+    assert_eq!(clicked, [false, false, false, true]);
 }
@@ -17,6 +17,7 @@ mod progress_bar;
 mod selected_label;
 mod separator;
 mod slider;
+mod spinner;
 pub(crate) mod text_edit;
 
 pub use hyperlink::*;
@@ -24,6 +25,7 @@ pub use label::*;
 pub use progress_bar::ProgressBar;
 pub use selected_label::*;
 pub use separator::*;
+pub use spinner::Spinner;
 pub use {button::*, drag_value::DragValue, image::Image, slider::*, text_edit::*};
 
 // ----------------------------------------------------------------------------
@@ -109,7 +111,11 @@ pub fn stroke_ui(ui: &mut crate::Ui, stroke: &mut epaint::Stroke, text: &str) {
 }
 
 pub(crate) fn shadow_ui(ui: &mut Ui, shadow: &mut epaint::Shadow, text: &str) {
-    let epaint::Shadow { extrusion, color } = shadow;
+    let epaint::Shadow {
+        extrusion,
+        color,
+        offset,
+    } = shadow;
     ui.horizontal(|ui| {
         ui.label(text);
         ui.add(
@@ -119,5 +125,9 @@ pub(crate) fn shadow_ui(ui: &mut Ui, shadow: &mut epaint::Shadow, text: &str) {
         )
         .on_hover_text("Extrusion");
         ui.color_edit_button_srgba(color);
+        ui.add(DragValue::new(&mut offset.x).speed(1.0))
+            .on_hover_text("Offset x");
+        ui.add(DragValue::new(&mut offset.y).speed(1.0))
+            .on_hover_text("Offset y");
     });
 }
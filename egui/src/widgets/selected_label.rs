@@ -100,3 +100,33 @@ impl Widget for SelectableLabel {
         response
     }
 }
+
+#[cfg(test)]
+#[test]
+fn hovered_selectable_label_is_highlighted() {
+    fn shapes_when_pointer_at(pointer_pos: Pos2) -> Vec<Shape> {
+        let mut ctx = CtxRef::default();
+        let mut shapes = vec![];
+        for _ in 0..2 {
+            ctx.begin_frame(RawInput {
+                events: vec![Event::PointerMoved(pointer_pos)],
+                ..Default::default()
+            });
+            Area::new("area")
+                .fixed_pos(Pos2::ZERO)
+                .show(&ctx, |ui| ui.selectable_label(false, "Label"));
+            shapes = ctx.end_frame().1;
+        }
+        shapes.into_iter().map(|clipped| clipped.1).collect()
+    }
+
+    let shapes = shapes_when_pointer_at(pos2(5.0, 5.0));
+    assert_eq!(shapes.len(), 2);
+    assert!(
+        matches!(shapes[0], Shape::Rect { .. }),
+        "the highlight is painted behind the text"
+    );
+
+    let shapes = shapes_when_pointer_at(pos2(500.0, 500.0));
+    assert_eq!(shapes.len(), 1, "no highlight when not hovered");
+}
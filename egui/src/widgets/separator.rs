@@ -68,16 +68,13 @@ impl Widget for Separator {
         };
 
         let (rect, response) = ui.allocate_at_least(size, Sense::hover());
+        let painter = ui.painter();
         let points = if is_horizontal_line {
-            [
-                pos2(rect.left(), rect.center().y),
-                pos2(rect.right(), rect.center().y),
-            ]
+            let y = painter.round_to_pixel(rect.center().y);
+            [pos2(rect.left(), y), pos2(rect.right(), y)]
         } else {
-            [
-                pos2(rect.center().x, rect.top()),
-                pos2(rect.center().x, rect.bottom()),
-            ]
+            let x = painter.round_to_pixel(rect.center().x);
+            [pos2(x, rect.top()), pos2(x, rect.bottom())]
         };
         let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
         ui.painter().line_segment(points, stroke);
@@ -19,6 +19,13 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
 
 // ----------------------------------------------------------------------------
 
+/// Where the handle was grabbed, relative to the pointer.
+/// Used for [`crate::style::Interaction::slider_grab_handle`].
+#[derive(Clone, Copy, Debug, Default)]
+struct GrabOffset(f32);
+
+// ----------------------------------------------------------------------------
+
 #[derive(Clone)]
 struct SliderSpec {
     logarithmic: bool,
@@ -31,6 +38,31 @@ struct SliderSpec {
     largest_finite: f64,
 }
 
+/// Maps to and from where the handle is on the slider, see [`Slider::custom_curve`].
+struct CustomCurve<'a> {
+    to_screen: Box<dyn 'a + Fn(f64) -> f64>,
+    from_screen: Box<dyn 'a + Fn(f64) -> f64>,
+}
+
+/// Where to show the text of a [`Slider`], relative to the slider itself.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SliderTextPosition {
+    /// To the right of the slider and its value (the default).
+    Right,
+    /// To the left of the slider.
+    Left,
+    /// On a row of its own above the slider.
+    Above,
+    /// Don't show the text, e.g. when the slider is already labeled by something else.
+    None,
+}
+
+impl Default for SliderTextPosition {
+    fn default() -> Self {
+        Self::Right
+    }
+}
+
 /// Control a number by a horizontal slider.
 ///
 /// The slider range defines the values you get when pulling the slider to the far edges.
@@ -55,6 +87,7 @@ pub struct Slider<'a> {
     get_set_value: GetSetValue<'a>,
     range: RangeInclusive<f64>,
     spec: SliderSpec,
+    custom_curve: Option<CustomCurve<'a>>,
     clamp_to_range: bool,
     smart_aim: bool,
     show_value: bool,
@@ -62,8 +95,11 @@ pub struct Slider<'a> {
     suffix: String,
     text: String,
     text_color: Option<Color32>,
+    text_position: SliderTextPosition,
+    custom_formatter: Option<super::drag_value::CustomFormatter<'a>>,
     min_decimals: usize,
     max_decimals: Option<usize>,
+    step: f64,
 }
 
 impl<'a> Slider<'a> {
@@ -95,6 +131,7 @@ impl<'a> Slider<'a> {
                 smallest_positive: 1e-6,
                 largest_finite: f64::INFINITY,
             },
+            custom_curve: None,
             clamp_to_range: false,
             smart_aim: true,
             show_value: true,
@@ -102,8 +139,11 @@ impl<'a> Slider<'a> {
             suffix: Default::default(),
             text: Default::default(),
             text_color: None,
+            text_position: Default::default(),
+            custom_formatter: None,
             min_decimals: 0,
             max_decimals: None,
+            step: 0.0,
         }
     }
 
@@ -126,6 +166,14 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Show the value formatted by this function instead of as a plain number,
+    /// e.g. `.custom_formatter(|v| format!("{:.0}%", 100.0 * v))`.
+    /// See [`DragValue::custom_formatter`].
+    pub fn custom_formatter(mut self, formatter: impl 'a + Fn(f64) -> String) -> Self {
+        self.custom_formatter = Some(Box::new(formatter));
+        self
+    }
+
     /// Show a text next to the slider (e.g. explaining what the slider controls).
     pub fn text(mut self, text: impl ToString) -> Self {
         self.text = text.to_string();
@@ -137,6 +185,19 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Where to show the [`Self::text`]. Default: [`SliderTextPosition::Right`].
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut volume: f32 = 0.0;
+    /// use egui::{Slider, SliderTextPosition};
+    /// ui.add(Slider::new(&mut volume, 0.0..=1.0).text("Volume").text_position(SliderTextPosition::Left));
+    /// ```
+    pub fn text_position(mut self, text_position: SliderTextPosition) -> Self {
+        self.text_position = text_position;
+        self
+    }
+
     /// Make this a logarithmic slider.
     /// This is great for when the slider spans a huge range,
     /// e.g. from one to a million.
@@ -162,6 +223,32 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Use a custom response curve, e.g. for gamma.
+    ///
+    /// `to_screen` gets where the value is in the slider range, from `0.0` to `1.0`
+    /// (after [`Self::logarithmic`], if that is on), and returns where to show the handle, also from `0.0` to `1.0`.
+    /// `from_screen` must be its inverse, or dragging the handle won't follow the pointer.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut brightness: f32 = 0.5;
+    /// ui.add(
+    ///     egui::Slider::new(&mut brightness, 0.0..=1.0)
+    ///         .custom_curve(|t| t.powf(1.0 / 2.2), |s| s.powf(2.2)),
+    /// );
+    /// ```
+    pub fn custom_curve(
+        mut self,
+        to_screen: impl 'a + Fn(f64) -> f64,
+        from_screen: impl 'a + Fn(f64) -> f64,
+    ) -> Self {
+        self.custom_curve = Some(CustomCurve {
+            to_screen: Box::new(to_screen),
+            from_screen: Box::new(from_screen),
+        });
+        self
+    }
+
     /// If set to `true`, all incoming and outgoing values will be clamped to the slider range.
     /// Default: `false`.
     pub fn clamp_to_range(mut self, clamp_to_range: bool) -> Self {
@@ -205,6 +292,16 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Snap all values to the closest multiple of `step`, counted from the start of the range,
+    /// e.g. `0.25` for a slider with the values `0.0, 0.25, 0.5, …`.
+    ///
+    /// The arrow keys will move the slider one step at a time.
+    /// Zero or negative means no snapping (default).
+    pub fn step_by(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
     /// Helper: equivalent to `self.precision(0).smallest_positive(1.0)`.
     /// If you use one of the integer constructors (e.g. `Slider::i32`) this is called for you,
     /// but if you want to have a slider for picking integer values in an `Slider::f64`, use this.
@@ -224,10 +321,19 @@ impl<'a> Slider<'a> {
     }
 
     fn set_value(&mut self, mut value: f64) {
+        let start = *self.range.start();
+        let end = *self.range.end();
+        let (min, max) = (start.min(end), start.max(end));
         if self.clamp_to_range {
-            let start = *self.range.start();
-            let end = *self.range.end();
-            value = value.clamp(start.min(end), start.max(end));
+            value = value.clamp(min, max);
+        }
+        if self.step > 0.0 {
+            let in_range = min <= value && value <= max;
+            value = start + ((value - start) / self.step).round() * self.step;
+            if in_range {
+                // Don't step outside the range (also not by a rounding error at the ends):
+                value = value.clamp(min, max);
+            }
         }
         if let Some(max_decimals) = self.max_decimals {
             value = emath::round_to_decimals(value, max_decimals);
@@ -249,12 +355,18 @@ impl<'a> Slider<'a> {
 
     /// For instance, `x` is the mouse position and `x_range` is the physical location of the slider on the screen.
     fn value_from_x(&self, x: f32, x_range: RangeInclusive<f32>) -> f64 {
-        let normalized = remap_clamp(x, x_range, 0.0..=1.0) as f64;
+        let mut normalized = remap_clamp(x, x_range, 0.0..=1.0) as f64;
+        if let Some(curve) = &self.custom_curve {
+            normalized = (curve.from_screen)(normalized).clamp(0.0, 1.0);
+        }
         value_from_normalized(normalized, self.range(), &self.spec)
     }
 
     fn x_from_value(&self, value: f64, x_range: RangeInclusive<f32>) -> f32 {
-        let normalized = normalized_from_value(value, self.range(), &self.spec);
+        let mut normalized = normalized_from_value(value, self.range(), &self.spec);
+        if let Some(curve) = &self.custom_curve {
+            normalized = (curve.to_screen)(normalized).clamp(0.0, 1.0);
+        }
         lerp(x_range, normalized as f32)
     }
 }
@@ -281,15 +393,35 @@ impl<'a> Slider<'a> {
         let rect = &response.rect;
         let x_range = x_range(rect);
 
-        if let Some(pointer_pos) = response.interact_pointer_pos() {
+        let mut pointer_x = response.interact_pointer_pos().map(|pos| pos.x);
+
+        if ui.style().interaction.slider_grab_handle {
+            let value = self.get_value();
+            let handle_x = self.x_from_value(value, x_range.clone());
+            pointer_x = pointer_x.and_then(|pointer_x| {
+                let mut memory = ui.memory();
+                if response.pressed() {
+                    if (handle_x - pointer_x).abs() <= handle_radius(rect) {
+                        let offset = GrabOffset(handle_x - pointer_x);
+                        memory.id_data_temp.insert(response.id, offset);
+                    } else {
+                        memory.id_data_temp.remove(&response.id);
+                    }
+                }
+                let offset = memory.id_data_temp.get::<GrabOffset>(&response.id)?.0;
+                Some(pointer_x + offset)
+            });
+        }
+
+        if let Some(pointer_x) = pointer_x {
             let new_value = if self.smart_aim {
                 let aim_radius = ui.input().aim_radius();
                 emath::smart_aim::best_in_range_f64(
-                    self.value_from_x(pointer_pos.x - aim_radius, x_range.clone()),
-                    self.value_from_x(pointer_pos.x + aim_radius, x_range.clone()),
+                    self.value_from_x(pointer_x - aim_radius, x_range.clone()),
+                    self.value_from_x(pointer_x + aim_radius, x_range.clone()),
                 )
             } else {
-                self.value_from_x(pointer_pos.x, x_range.clone())
+                self.value_from_x(pointer_x, x_range.clone())
             };
             self.set_value(new_value);
         }
@@ -297,27 +429,53 @@ impl<'a> Slider<'a> {
         let value = self.get_value();
         response.widget_info(|| WidgetInfo::slider(value, &self.text));
 
+        let mut nudge = 0.0;
         if response.has_focus() {
-            let kb_step = ui.input().num_presses(Key::ArrowRight) as f32
+            nudge = ui.input().num_presses(Key::ArrowRight) as f32
                 - ui.input().num_presses(Key::ArrowLeft) as f32;
-
-            if kb_step != 0.0 {
-                let prev_value = self.get_value();
-                let prev_x = self.x_from_value(prev_value, x_range.clone());
-                let new_x = prev_x + kb_step;
-                let new_value = if self.smart_aim {
-                    let aim_radius = ui.input().aim_radius();
-                    emath::smart_aim::best_in_range_f64(
-                        self.value_from_x(new_x - aim_radius, x_range.clone()),
-                        self.value_from_x(new_x + aim_radius, x_range.clone()),
-                    )
-                } else {
-                    self.value_from_x(new_x, x_range.clone())
-                };
-                self.set_value(new_value);
+            if ui.input().modifiers.shift {
+                nudge *= 10.0; // take larger steps
             }
         }
 
+        let scroll_delta = response.take_value_scroll_delta();
+        let scroll_points = scroll_delta.x + scroll_delta.y; // Increase when scrolling up
+        if scroll_points != 0.0 {
+            nudge += if self.step > 0.0 {
+                scroll_points.signum() // one step at a time
+            } else {
+                scroll_points // move the handle as far as we scrolled
+            };
+        }
+
+        if nudge != 0.0 && self.step > 0.0 {
+            let direction = if self.range.start() <= self.range.end() {
+                1.0
+            } else {
+                -1.0
+            };
+            let (min, max) = (
+                self.range.start().min(*self.range.end()),
+                self.range.start().max(*self.range.end()),
+            );
+            let new_value = self.get_value() + direction * nudge as f64 * self.step;
+            self.set_value(new_value.clamp(min, max));
+        } else if nudge != 0.0 {
+            let prev_value = self.get_value();
+            let prev_x = self.x_from_value(prev_value, x_range.clone());
+            let new_x = prev_x + nudge;
+            let new_value = if self.smart_aim {
+                let aim_radius = ui.input().aim_radius();
+                emath::smart_aim::best_in_range_f64(
+                    self.value_from_x(new_x - aim_radius, x_range.clone()),
+                    self.value_from_x(new_x + aim_radius, x_range.clone()),
+                )
+            } else {
+                self.value_from_x(new_x, x_range.clone())
+            };
+            self.set_value(new_value);
+        }
+
         // Paint it:
         {
             let value = self.get_value();
@@ -353,6 +511,18 @@ impl<'a> Slider<'a> {
         }
     }
 
+    /// Returns the response of the slider.
+    fn slider_and_value_ui(&mut self, ui: &mut Ui, height: f32) -> Response {
+        let slider_response = self.allocate_slider_space(ui, height);
+        self.slider_ui(ui, &slider_response);
+
+        if self.show_value {
+            let x_range = x_range(&slider_response.rect);
+            self.value_ui(ui, x_range);
+        }
+        slider_response
+    }
+
     fn label_ui(&mut self, ui: &mut Ui) {
         if !self.text.is_empty() {
             let text_color = self.text_color.unwrap_or_else(|| ui.visuals().text_color());
@@ -362,15 +532,17 @@ impl<'a> Slider<'a> {
 
     fn value_ui(&mut self, ui: &mut Ui, x_range: RangeInclusive<f32>) {
         let mut value = self.get_value();
-        ui.add(
-            DragValue::new(&mut value)
-                .speed(self.current_gradient(&x_range))
-                .clamp_range(self.clamp_range())
-                .min_decimals(self.min_decimals)
-                .max_decimals_opt(self.max_decimals)
-                .suffix(self.suffix.clone())
-                .prefix(self.prefix.clone()),
-        );
+        let mut drag_value = DragValue::new(&mut value)
+            .speed(self.current_gradient(&x_range))
+            .clamp_range(self.clamp_range())
+            .min_decimals(self.min_decimals)
+            .max_decimals_opt(self.max_decimals)
+            .suffix(self.suffix.clone())
+            .prefix(self.prefix.clone());
+        if let Some(custom_formatter) = self.custom_formatter.take() {
+            drag_value = drag_value.custom_formatter(custom_formatter);
+        }
+        ui.add(drag_value);
         if value != self.get_value() {
             self.set_value(value);
         }
@@ -398,20 +570,26 @@ impl<'a> Widget for Slider<'a> {
 
         let old_value = self.get_value();
 
-        let inner_response = ui.horizontal(|ui| {
-            let slider_response = self.allocate_slider_space(ui, height);
-            self.slider_ui(ui, &slider_response);
-
-            if self.show_value {
-                let x_range = x_range(&slider_response.rect);
-                self.value_ui(ui, x_range);
-            }
-
-            if !self.text.is_empty() {
+        let text_position = self.text_position;
+        let inner_response = if text_position == SliderTextPosition::Above && !self.text.is_empty()
+        {
+            ui.vertical(|ui| {
                 self.label_ui(ui);
-            }
-            slider_response
-        });
+                ui.horizontal(|ui| self.slider_and_value_ui(ui, height))
+                    .inner
+            })
+        } else {
+            ui.horizontal(|ui| {
+                if text_position == SliderTextPosition::Left {
+                    self.label_ui(ui);
+                }
+                let slider_response = self.slider_and_value_ui(ui, height);
+                if text_position == SliderTextPosition::Right {
+                    self.label_ui(ui);
+                }
+                slider_response
+            })
+        };
 
         let mut response = inner_response.inner | inner_response.response;
         response.changed = self.get_value() != old_value;
@@ -572,3 +750,270 @@ fn logaritmic_zero_cutoff(min: f64, max: f64) -> f64 {
     crate::egui_assert!(0.0 <= cutoff && cutoff <= 1.0);
     cutoff
 }
+
+#[cfg(test)]
+#[test]
+fn slider_step_by() {
+    let mut value = 0.0_f64;
+    let mut slider = Slider::new(&mut value, 0.0..=1.0).step_by(0.25);
+    for i in 0..=100 {
+        let x = i as f32;
+        let new_value = slider.value_from_x(x, 0.0..=100.0);
+        slider.set_value(new_value);
+        let value = slider.get_value();
+        assert!((0.0..=1.0).contains(&value));
+        assert_eq!(value % 0.25, 0.0, "{} is not a multiple of the step", value);
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn slider_step_by_reaches_the_end() {
+    // 0.1 is not exact in binary, so `3.0 * 0.1` is slightly more than `0.3`:
+    let mut value = 0.0_f64;
+    let mut slider = Slider::new(&mut value, 0.0..=0.3).step_by(0.1);
+    slider.set_value(slider.value_from_x(100.0, 0.0..=100.0));
+    assert_eq!(slider.get_value(), 0.3);
+    slider.set_value(slider.value_from_x(0.0, 0.0..=100.0));
+    assert_eq!(slider.get_value(), 0.0);
+}
+
+#[cfg(test)]
+#[test]
+fn slider_custom_curve() {
+    let mut value = 0.0_f64;
+    let slider = Slider::new(&mut value, 0.0..=100.0)
+        .smart_aim(false)
+        .custom_curve(|t| t.sqrt(), |s| s * s);
+    assert_eq!(slider.x_from_value(25.0, 0.0..=10.0), 5.0);
+    assert_eq!(slider.value_from_x(5.0, 0.0..=10.0), 25.0);
+    assert_eq!(slider.value_from_x(-5.0, 0.0..=10.0), 0.0);
+    assert_eq!(slider.x_from_value(100.0, 0.0..=10.0), 10.0);
+}
+
+#[cfg(test)]
+#[test]
+fn dragging_slider_over_button() {
+    let mut ctx = CtxRef::default();
+    let mut value = 0.0;
+    let mut button_hovered = false;
+    let mut button_clicked = false;
+    let mut slider_pressed_frames = vec![];
+    let mut slider_rect = Rect::NOTHING;
+    let mut button_rect = Rect::NOTHING;
+
+    for frame in 0..6 {
+        let events = match frame {
+            1 => vec![Event::PointerMoved(slider_rect.center())],
+            2 => vec![Event::PointerButton {
+                pos: slider_rect.center(),
+                button: PointerButton::Primary,
+                pressed: true,
+                modifiers: Default::default(),
+            }],
+            3 => vec![Event::PointerMoved(button_rect.center())],
+            4 => vec![Event::PointerButton {
+                pos: button_rect.center(),
+                button: PointerButton::Primary,
+                pressed: false,
+                modifiers: Default::default(),
+            }],
+            _ => vec![],
+        };
+        ctx.begin_frame(RawInput {
+            events,
+            ..Default::default()
+        });
+        CentralPanel::default().show(&ctx, |ui| {
+            let slider = ui.add(Slider::new(&mut value, 0.0..=1.0));
+            slider_rect = slider.rect;
+            if slider.pressed() {
+                slider_pressed_frames.push(frame);
+            }
+            let button = ui.button("Button");
+            button_rect = button.rect;
+            if (2..=4).contains(&frame) {
+                button_hovered |= button.hovered();
+                button_clicked |= button.clicked();
+            }
+        });
+        let _ = ctx.end_frame();
+    }
+
+    assert!(value > 0.0, "the slider should have been dragged");
+    assert_eq!(slider_pressed_frames, vec![2]);
+    assert!(!button_hovered);
+    assert!(!button_clicked);
+}
+
+#[cfg(test)]
+#[test]
+fn slider_grab_handle() {
+    let mut ctx = CtxRef::default();
+    let mut style = (*ctx.style()).clone();
+    style.interaction.slider_grab_handle = true;
+    ctx.set_style(style);
+
+    let mut value = 0.0;
+    let mut slider_rect = Rect::NOTHING;
+    for frame in 0..4 {
+        let press_pos = pos2(slider_rect.right() - 1.0, slider_rect.center().y);
+        let events = match frame {
+            1 => vec![Event::PointerMoved(press_pos)],
+            2 => vec![Event::PointerButton {
+                pos: press_pos,
+                button: PointerButton::Primary,
+                pressed: true,
+                modifiers: Default::default(),
+            }],
+            _ => vec![],
+        };
+        ctx.begin_frame(RawInput {
+            events,
+            ..Default::default()
+        });
+        CentralPanel::default().show(&ctx, |ui| {
+            slider_rect = ui.add(Slider::new(&mut value, 0.0..=1.0)).rect;
+        });
+        let _ = ctx.end_frame();
+    }
+    assert_eq!(
+        value, 0.0,
+        "pressing away from the handle should not move it"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn slider_arrow_keys() {
+    let mut ctx = CtxRef::default();
+    let mut value = 0.5_f64;
+    let shift = Modifiers {
+        shift: true,
+        ..Default::default()
+    };
+    let frames = vec![
+        (vec![], Modifiers::default(), 0.5),
+        (vec![Key::ArrowRight], Modifiers::default(), 0.6),
+        (vec![Key::ArrowLeft], shift, 0.0), // clamped
+        (vec![Key::ArrowRight; 3], Modifiers::default(), 0.3),
+    ];
+
+    for (keys, modifiers, expected) in frames {
+        let events = keys
+            .into_iter()
+            .map(|key| Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+            })
+            .collect();
+        ctx.begin_frame(RawInput {
+            events,
+            modifiers,
+            ..Default::default()
+        });
+        CentralPanel::default().show(&ctx, |ui| {
+            ui.add(Slider::new(&mut value, 0.0..=1.0).step_by(0.1))
+                .request_focus();
+        });
+        let _ = ctx.end_frame();
+        assert!((value - expected).abs() < 1e-6, "{} != {}", value, expected);
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn scroll_over_slider_in_scroll_area() {
+    let mut ctx = CtxRef::default();
+    let mut style = (*ctx.style()).clone();
+    style.interaction.scroll_changes_values = true;
+    ctx.set_style(style);
+
+    let mut value = 0.0;
+    let mut slider_rects = vec![];
+    for frame in 0..4 {
+        let pointer_pos = slider_rects.last().map_or(Pos2::ZERO, Rect::center);
+        ctx.begin_frame(RawInput {
+            events: vec![Event::PointerMoved(pointer_pos)],
+            scroll_delta: if frame == 2 {
+                vec2(0.0, -10.0)
+            } else {
+                Vec2::ZERO
+            },
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 200.0))),
+            ..Default::default()
+        });
+        CentralPanel::default().show(&ctx, |ui| {
+            ScrollArea::vertical().show(ui, |ui| {
+                slider_rects.push(ui.add(Slider::new(&mut value, -1.0..=1.0)).rect);
+                ui.allocate_space(vec2(100.0, 1000.0));
+            });
+        });
+        let _ = ctx.end_frame();
+    }
+
+    assert!(
+        value < 0.0,
+        "scrolling down over the slider should decrease it"
+    );
+    assert_eq!(
+        slider_rects[0].top(),
+        slider_rects[3].top(),
+        "the scroll area should not have scrolled"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn slider_text_positions() {
+    /// Returns the rect of the slider response and the position of its text.
+    fn layout_slider(text_position: SliderTextPosition) -> (Rect, Option<Pos2>) {
+        let mut ctx = CtxRef::default();
+        ctx.begin_frame(Default::default());
+        let mut value = 0.5;
+        let mut slider_rect = Rect::NOTHING;
+        CentralPanel::default().show(&ctx, |ui| {
+            slider_rect = ui
+                .add(
+                    Slider::new(&mut value, 0.0..=1.0)
+                        .show_value(false)
+                        .text("Text")
+                        .text_position(text_position),
+                )
+                .rect;
+        });
+        let (_, shapes) = ctx.end_frame();
+        let text_pos = shapes.iter().find_map(|clipped| match &clipped.1 {
+            Shape::Text(text) if text.galley.text() == "Text" => Some(text.pos),
+            _ => None,
+        });
+        (slider_rect, text_pos)
+    }
+
+    // The response covers both the slider and its text:
+    let (right, text_pos) = layout_slider(SliderTextPosition::Right);
+    assert!(
+        text_pos.unwrap().x > right.center().x,
+        "text after the slider"
+    );
+
+    let (left, text_pos) = layout_slider(SliderTextPosition::Left);
+    assert!(
+        text_pos.unwrap().x < left.center().x,
+        "text before the slider"
+    );
+
+    let (above, text_pos) = layout_slider(SliderTextPosition::Above);
+    assert_eq!(text_pos.unwrap().y, above.top());
+    assert!(
+        above.height() > right.height(),
+        "slider on the row below the text"
+    );
+    assert!(above.width() < right.width(), "text not next to the slider");
+
+    let (none, text_pos) = layout_slider(SliderTextPosition::None);
+    assert_eq!(text_pos, None);
+    assert_eq!(none.height(), right.height());
+    assert!(none.width() < right.width(), "no room for the text");
+}
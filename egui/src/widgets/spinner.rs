@@ -0,0 +1,60 @@
+use crate::*;
+
+/// A spinner widget used to indicate loading.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// ui.add(egui::Spinner::new());
+/// ui.spinner(); // same as above
+/// ```
+///
+/// The spinner is animated, so it will keep requesting repaints while it is visible.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Default)]
+pub struct Spinner {
+    /// Uses the style's `interact_size` if `None`.
+    size: Option<f32>,
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The width and height of the spinner. Defaults to the height of an interactive widget.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl Widget for Spinner {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let size = self
+            .size
+            .unwrap_or_else(|| ui.spacing().interact_size.y)
+            .at_least(0.0);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::hover());
+
+        if ui.clip_rect().intersects(rect) {
+            ui.ctx().request_repaint();
+
+            let radius = (rect.height() / 2.0) - 2.0;
+            let n_points = 20;
+            let time = ui.input().time;
+            let start_angle = time * 360f64.to_radians();
+            let end_angle = start_angle + 240f64.to_radians() * time.sin();
+            let points: Vec<Pos2> = (0..n_points)
+                .map(|i| {
+                    let angle = lerp(start_angle..=end_angle, i as f64 / n_points as f64);
+                    let (sin, cos) = angle.sin_cos();
+                    rect.center() + radius * vec2(cos as f32, sin as f32)
+                })
+                .collect();
+            ui.painter()
+                .add(Shape::line(points, ui.visuals().widgets.inactive.fg_stroke));
+        }
+
+        response
+    }
+}
@@ -83,6 +83,14 @@ impl CursorPair {
             [self.secondary, self.primary]
         }
     }
+
+    /// The selected range of characters (NOT bytes!), from the first to the last selected character.
+    ///
+    /// The range is empty if nothing is selected.
+    pub fn char_range(&self) -> Range<usize> {
+        let [min, max] = self.sorted();
+        min.ccursor.index..max.ccursor.index
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -118,7 +126,14 @@ impl CCursorPair {
 /// an underlying buffer.
 ///
 /// Most likely you will use a `String` which implements `TextBuffer`.
+/// A `&str` is also a `TextBuffer`, but an immutable one:
+/// the text can be selected and copied, but not edited.
 pub trait TextBuffer: AsRef<str> + Into<String> {
+    /// Can this text be edited?
+    fn is_mutable(&self) -> bool {
+        true
+    }
+
     /// Inserts text `text` into this buffer at character index `ch_idx`.
     ///
     /// # Notes
@@ -196,6 +211,19 @@ impl TextBuffer for String {
     }
 }
 
+/// Immutable view of a `&str`: all edits are ignored.
+impl<'a> TextBuffer for &'a str {
+    fn is_mutable(&self) -> bool {
+        false
+    }
+
+    fn insert_text(&mut self, _text: &str, _ch_idx: usize) -> usize {
+        0
+    }
+
+    fn delete_char_range(&mut self, _ch_range: Range<usize>) {}
+}
+
 /// A text region that the user can edit the contents of.
 ///
 /// See also [`Ui::text_edit_singleline`] and  [`Ui::text_edit_multiline`].
@@ -761,6 +789,9 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
                     _ => None,
                 };
 
+                // An immutable buffer ignores all edits, so keep the cursor where it was:
+                let did_mutate_text = did_mutate_text.filter(|_| text.is_mutable());
+
                 if let Some(new_ccursorp) = did_mutate_text {
                     response.mark_changed();
 
@@ -827,7 +858,14 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             if let Some(cursorp) = state.cursorp {
                 // We paint the cursor on top of the text, in case
                 // the text galley has backgrounds (as e.g. `code` snippets in markup do).
-                paint_cursor_selection(ui, &painter, text_draw_pos, &galley, &cursorp);
+                paint_cursor_selection(
+                    ui,
+                    &painter,
+                    text_draw_pos,
+                    &galley,
+                    &cursorp,
+                    state.has_ime,
+                );
                 paint_cursor_end(
                     ui,
                     row_height,
@@ -867,9 +905,8 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
                 )
             });
         } else if selection_changed {
-            let text_cursor = text_cursor.unwrap();
-            let char_range =
-                text_cursor.primary.ccursor.index..=text_cursor.secondary.ccursor.index;
+            let char_range = text_cursor.unwrap().char_range();
+            let char_range = char_range.start..=char_range.end;
             let info = WidgetInfo::text_selection_changed(
                 char_range,
                 mask_if_password(password, text.as_str()),
@@ -899,6 +936,7 @@ fn paint_cursor_selection(
     pos: Pos2,
     galley: &Galley,
     cursorp: &CursorPair,
+    is_ime_preedit: bool,
 ) {
     if cursorp.is_empty() {
         return;
@@ -906,6 +944,8 @@ fn paint_cursor_selection(
 
     // We paint the cursor selection on top of the text, so make it transparent:
     let color = ui.visuals().selection.bg_fill.linear_multiply(0.5);
+    // While composing, the selection is the not yet committed IME text, which we underline instead:
+    let preedit_stroke = Stroke::new(1.0, ui.visuals().text_color());
     let [min, max] = cursorp.sorted();
     let min = min.rcursor;
     let max = max.rcursor;
@@ -927,11 +967,21 @@ fn paint_cursor_selection(
             };
             row.rect.right() + newline_size
         };
-        let rect = Rect::from_min_max(
-            pos + vec2(left, row.min_y()),
-            pos + vec2(right, row.max_y()),
-        );
-        painter.rect_filled(rect, 0.0, color);
+        if is_ime_preedit {
+            painter.line_segment(
+                [
+                    pos + vec2(left, row.max_y()),
+                    pos + vec2(right, row.max_y()),
+                ],
+                preedit_stroke,
+            );
+        } else {
+            let rect = Rect::from_min_max(
+                pos + vec2(left, row.min_y()),
+                pos + vec2(right, row.max_y()),
+            );
+            painter.rect_filled(rect, 0.0, color);
+        }
     }
 }
 
@@ -1339,3 +1389,88 @@ fn decrease_identation<S: TextBuffer>(ccursor: &mut CCursor, text: &mut S) {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn selectable_text_can_be_copied_but_not_edited() {
+    let mut ctx = CtxRef::default();
+    let pos = pos2(20.0, 12.0);
+    let select_all = Event::Key {
+        key: Key::A,
+        pressed: true,
+        modifiers: Modifiers {
+            command: true,
+            ..Default::default()
+        },
+    };
+    let events = vec![
+        vec![Event::PointerMoved(pos)],
+        vec![Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed: true,
+            modifiers: Default::default(),
+        }],
+        vec![Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed: false,
+            modifiers: Default::default(),
+        }],
+        vec![
+            Event::Text("x".to_owned()),
+            Event::Key {
+                key: Key::Backspace,
+                pressed: true,
+                modifiers: Default::default(),
+            },
+        ],
+        vec![select_all, Event::Copy],
+    ];
+
+    let mut changed = false;
+    let mut copied_text = String::new();
+    for events in events {
+        ctx.begin_frame(RawInput {
+            events,
+            ..Default::default()
+        });
+        CentralPanel::default().show(&ctx, |ui| {
+            changed |= ui.selectable_text("Copy me").changed();
+        });
+        let (output, _) = ctx.end_frame();
+        copied_text += &output.copied_text;
+    }
+    assert!(!changed);
+    assert_eq!(copied_text, "Copy me");
+}
+
+#[cfg(test)]
+#[test]
+fn ime_composition_is_replaced_when_committed() {
+    let mut ctx = CtxRef::default();
+    let mut text = String::from("a");
+    let events = vec![
+        vec![],
+        vec![Event::CompositionStart],
+        vec![Event::CompositionUpdate("ni".to_owned())],
+        vec![Event::CompositionUpdate("nih".to_owned())],
+        vec![Event::CompositionEnd("你好".to_owned())],
+    ];
+    let mut texts = vec![];
+    for (frame, events) in events.into_iter().enumerate() {
+        ctx.begin_frame(RawInput {
+            events,
+            ..Default::default()
+        });
+        CentralPanel::default().show(&ctx, |ui| {
+            let response = ui.text_edit_singleline(&mut text);
+            if frame == 0 {
+                response.request_focus(); // the cursor starts at the end
+            }
+        });
+        let _ = ctx.end_frame();
+        texts.push(text.clone());
+    }
+    assert_eq!(texts, ["a", "a", "ani", "anih", "a你好"]);
+}
@@ -1,8 +1,42 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 use egui::epaint::TextShape;
 use egui_demo_lib::LOREM_IPSUM_LONG;
 
+/// Counts the allocations, so that the benchmarks can also report how much garbage they make.
+struct CountingAllocator;
+
+static NUM_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        NUM_ALLOCATIONS.fetch_add(1, Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        NUM_ALLOCATIONS.fetch_add(1, Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// How many allocations (and reallocations) `f` makes.
+fn count_allocations<R>(f: impl FnOnce() -> R) -> usize {
+    let before = NUM_ALLOCATIONS.load(Relaxed);
+    black_box(f());
+    NUM_ALLOCATIONS.load(Relaxed) - before
+}
+
 pub fn criterion_benchmark(c: &mut Criterion) {
     let raw_input = egui::RawInput::default();
 
@@ -49,6 +83,26 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         });
     }
 
+    {
+        // A frame with many labels. Each should allocate only its text and a list of sections.
+        let mut ctx = egui::CtxRef::default();
+        let mut label_heavy_frame = || {
+            ctx.begin_frame(raw_input.clone());
+            egui::CentralPanel::default().show(&ctx, |ui| {
+                for _ in 0..100 {
+                    ui.label("the quick brown fox jumps over the lazy dog");
+                }
+            });
+            ctx.end_frame()
+        };
+        label_heavy_frame(); // Fill the galley cache
+        println!(
+            "label_heavy_frame: {} allocations per frame",
+            count_allocations(&mut label_heavy_frame)
+        );
+        c.bench_function("label_heavy_frame", |b| b.iter(&mut label_heavy_frame));
+    }
+
     {
         let mut ctx = egui::CtxRef::default();
         ctx.begin_frame(raw_input);
@@ -87,6 +141,22 @@ pub fn criterion_benchmark(c: &mut Criterion) {
                 layout(&fonts, job.into())
             })
         });
+        let texts: Vec<String> = (0..100).map(|i| format!("Label number {}", i)).collect();
+        let layout_many_labels = || {
+            use egui::epaint::text::{layout, LayoutJob};
+
+            for text in &texts {
+                let job = LayoutJob::simple(text.clone(), text_style, color, wrap_width);
+                black_box(layout(&fonts, job.into()));
+            }
+        };
+        println!(
+            "text_layout_many_labels_uncached: {} allocations per iteration",
+            count_allocations(layout_many_labels)
+        );
+        c.bench_function("text_layout_many_labels_uncached", |b| {
+            b.iter(layout_many_labels)
+        });
         c.bench_function("text_layout_cached", |b| {
             b.iter(|| fonts.layout(LOREM_IPSUM_LONG.to_owned(), text_style, color, wrap_width))
         });
@@ -186,6 +186,22 @@ where
     }
 }
 
+/// Round a value in points to the closest physical pixel boundary.
+///
+/// Use this to make e.g. 1px lines and rectangle edges look crisp.
+///
+/// ```
+/// # use emath::round_to_pixel;
+/// assert_eq!(round_to_pixel(10.3, 1.0), 10.0);
+/// assert_eq!(round_to_pixel(10.3, 2.0), 10.5);
+/// assert_eq!(round_to_pixel(10.2, 1.5), 10.0);
+/// assert_eq!(round_to_pixel(10.5, 1.5) * 1.5, 16.0);
+/// ```
+#[inline(always)]
+pub fn round_to_pixel(value: f32, pixels_per_point: f32) -> f32 {
+    (value * pixels_per_point).round() / pixels_per_point
+}
+
 /// Round a value to the given number of decimal places.
 pub fn round_to_decimals(value: f64, decimal_places: usize) -> f64 {
     // This is a stupid way of doing this, but stupid works.
@@ -166,6 +166,9 @@ impl Rect {
     }
 
     /// The intersection of two `Rect`, i.e. the area covered by both.
+    ///
+    /// If the two rectangles don't overlap, the result is negative (see [`Self::is_negative`]).
+    /// Use [`Self::intersection`] if you want `None` in that case.
     #[must_use]
     pub fn intersect(self, other: Rect) -> Self {
         Self {
@@ -174,6 +177,19 @@ impl Rect {
         }
     }
 
+    /// The intersection of two `Rect`, or `None` if they don't overlap.
+    ///
+    /// Rectangles that only share an edge intersect in a rectangle of zero width or height.
+    #[must_use]
+    pub fn intersection(self, other: Rect) -> Option<Rect> {
+        let rect = self.intersect(other);
+        if rect.is_negative() {
+            None
+        } else {
+            Some(rect)
+        }
+    }
+
     #[must_use]
     #[inline]
     pub fn intersects(self, other: Rect) -> bool {
@@ -198,12 +214,23 @@ impl Rect {
         *self = self.translate(center - self.center());
     }
 
+    /// Is the point inside the rectangle or on any of its edges?
     #[must_use]
     #[inline(always)]
     pub fn contains(&self, p: Pos2) -> bool {
         self.min.x <= p.x && p.x <= self.max.x && self.min.y <= p.y && p.y <= self.max.y
     }
 
+    /// Like [`Self::contains`], but excludes the right and bottom edges.
+    ///
+    /// Use this for hit-testing, so that a point on the edge shared by
+    /// two adjacent rectangles is only inside one of them.
+    #[must_use]
+    #[inline(always)]
+    pub fn contains_half_open(&self, p: Pos2) -> bool {
+        self.min.x <= p.x && p.x < self.max.x && self.min.y <= p.y && p.y < self.max.y
+    }
+
     #[must_use]
     pub fn contains_rect(&self, other: Rect) -> bool {
         self.contains(other.min) && self.contains(other.max)
@@ -453,3 +480,36 @@ impl From<[Pos2; 2]> for Rect {
         Self { min, max }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn contains_on_the_boundary() {
+        let left = Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0));
+        let right = Rect::from_min_max(pos2(10.0, 0.0), pos2(20.0, 10.0));
+        let edge = pos2(10.0, 5.0);
+
+        assert!(left.contains(edge) && right.contains(edge));
+        assert!(!left.contains_half_open(edge));
+        assert!(right.contains_half_open(edge));
+
+        assert!(left.contains_half_open(left.min));
+        assert!(!left.contains_half_open(left.max));
+    }
+
+    #[test]
+    fn intersect_with_clip_rect() {
+        let clip_rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+        let partially_visible = Rect::from_min_max(pos2(80.0, 40.0), pos2(120.0, 60.0));
+        let hidden = Rect::from_min_max(pos2(0.0, 60.0), pos2(100.0, 70.0));
+
+        assert_eq!(
+            partially_visible.intersection(clip_rect),
+            Some(Rect::from_min_max(pos2(80.0, 40.0), pos2(100.0, 50.0)))
+        );
+        assert_eq!(hidden.intersection(clip_rect), None);
+        assert!(hidden.intersect(clip_rect).is_negative());
+    }
+}
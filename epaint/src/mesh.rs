@@ -170,6 +170,23 @@ impl Mesh {
         self.add_rect_with_uv(rect, [WHITE_UV, WHITE_UV].into(), color)
     }
 
+    /// Rectangle with a color for each corner: `[left_top, right_top, left_bottom, right_bottom]`.
+    ///
+    /// The colors are interpolated across the rectangle, which makes for a gradient.
+    pub fn add_gradient_rect(&mut self, rect: Rect, colors: [Color32; 4]) {
+        #![allow(clippy::identity_op)]
+        crate::epaint_assert!(self.texture_id == TextureId::Egui);
+
+        let idx = self.vertices.len() as u32;
+        self.add_triangle(idx + 0, idx + 1, idx + 2);
+        self.add_triangle(idx + 2, idx + 1, idx + 3);
+
+        self.colored_vertex(rect.left_top(), colors[0]);
+        self.colored_vertex(rect.right_top(), colors[1]);
+        self.colored_vertex(rect.left_bottom(), colors[2]);
+        self.colored_vertex(rect.right_bottom(), colors[3]);
+    }
+
     /// This is for platforms that only support 16-bit index buffers.
     ///
     /// Splits this mesh into many smaller meshes (if needed)
@@ -11,6 +11,9 @@ pub struct Shadow {
 
     /// Color of the opaque center of the shadow.
     pub color: Color32,
+
+    /// Move the shadow this much relative to the rect, e.g. `vec2(4.0, 4.0)` for a drop shadow.
+    pub offset: emath::Vec2,
 }
 
 impl Shadow {
@@ -19,6 +22,7 @@ impl Shadow {
         Self {
             extrusion: 16.0,
             color: Color32::from_black_alpha(96),
+            offset: emath::Vec2::ZERO,
         }
     }
 
@@ -27,6 +31,7 @@ impl Shadow {
         Self {
             extrusion: 16.0,
             color: Color32::from_black_alpha(32),
+            offset: emath::Vec2::ZERO,
         }
     }
 
@@ -35,6 +40,7 @@ impl Shadow {
         Self {
             extrusion: 32.0,
             color: Color32::from_black_alpha(96),
+            offset: emath::Vec2::ZERO,
         }
     }
 
@@ -43,17 +49,22 @@ impl Shadow {
         Self {
             extrusion: 32.0,
             color: Color32::from_black_alpha(40),
+            offset: emath::Vec2::ZERO,
         }
     }
 
     pub fn tessellate(&self, rect: emath::Rect, corner_radius: f32) -> Mesh {
         // tessellator.clip_rect = clip_rect; // TODO: culling
 
-        let Self { extrusion, color } = *self;
+        let Self {
+            extrusion,
+            color,
+            offset,
+        } = *self;
 
         use crate::tessellator::*;
         let rect = PaintRect {
-            rect: rect.expand(0.5 * extrusion),
+            rect: rect.translate(offset).expand(0.5 * extrusion),
             corner_radius: corner_radius + 0.5 * extrusion,
             fill: color,
             stroke: Default::default(),
@@ -151,6 +151,20 @@ impl Shape {
         }
     }
 
+    /// A rectangle going smoothly from `top_color` to `bottom_color`.
+    pub fn vertical_gradient(rect: Rect, top_color: Color32, bottom_color: Color32) -> Self {
+        let mut mesh = Mesh::default();
+        mesh.add_gradient_rect(rect, [top_color, top_color, bottom_color, bottom_color]);
+        Self::mesh(mesh)
+    }
+
+    /// A rectangle going smoothly from `left_color` to `right_color`.
+    pub fn horizontal_gradient(rect: Rect, left_color: Color32, right_color: Color32) -> Self {
+        let mut mesh = Mesh::default();
+        mesh.add_gradient_rect(rect, [left_color, right_color, left_color, right_color]);
+        Self::mesh(mesh)
+    }
+
     #[allow(clippy::needless_pass_by_value)]
     pub fn text(
         fonts: &Fonts,
@@ -302,7 +302,7 @@ impl TessellationOptions {
 impl TessellationOptions {
     #[inline(always)]
     pub fn round_to_pixel(&self, point: f32) -> f32 {
-        (point * self.pixels_per_point).round() / self.pixels_per_point
+        emath::round_to_pixel(point, self.pixels_per_point)
     }
 }
 
@@ -762,7 +762,8 @@ impl Tessellator {
 /// Turns [`Shape`]:s into sets of triangles.
 ///
 /// The given shapes will be painted back-to-front (painters algorithm).
-/// They will be batched together by clip rectangle.
+/// Consecutive shapes with the same clip rectangle and texture are batched into the same [`Mesh`],
+/// so e.g. a whole panel of text and frames is usually a single draw call.
 ///
 /// * `shapes`: the shape to tessellate
 /// * `options`: tessellation quality
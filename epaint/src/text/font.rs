@@ -186,6 +186,12 @@ impl FontImpl {
         self.height_in_points
     }
 
+    /// Distance from the top of a row of text down to the baseline. In points
+    #[inline(always)]
+    pub fn ascent(&self) -> f32 {
+        self.height_in_points + self.y_offset
+    }
+
     #[inline(always)]
     pub fn pixels_per_point(&self) -> f32 {
         self.pixels_per_point
@@ -196,6 +202,11 @@ type FontIndex = usize;
 
 // TODO: rename?
 /// Wrapper over multiple `FontImpl` (e.g. a primary + fallbacks for emojis)
+///
+/// Each character is looked up in the fonts in order, and the first font that has it is used,
+/// so a single row of text can mix glyphs from several fonts.
+/// Only if none of the fonts has the character is a replacement character (`◻`) shown.
+/// The order comes from [`crate::text::FontDefinitions::fonts_for_family`].
 pub struct Font {
     text_style: TextStyle,
     fonts: Vec<Arc<FontImpl>>,
@@ -288,6 +299,11 @@ impl Font {
         self.row_height
     }
 
+    /// Distance from the top of a row of text down to the baseline of the primary font. In points
+    pub fn ascent(&self) -> f32 {
+        self.fonts.first().map_or(0.0, |font| font.ascent())
+    }
+
     pub fn uv_rect(&self, c: char) -> UvRect {
         self.glyph_info_cache
             .read()
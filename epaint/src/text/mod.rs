@@ -6,7 +6,7 @@ mod fonts;
 mod text_layout;
 mod text_layout_types;
 
-/// One `\t` character is this many spaces wide.
+/// Tab stops are this many spaces apart by default, see [`LayoutJob::tab_size`].
 pub const TAB_SIZE: usize = 4;
 
 pub use {
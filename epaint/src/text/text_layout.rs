@@ -1,8 +1,8 @@
 use std::ops::RangeInclusive;
 use std::sync::Arc;
 
-use super::{Fonts, Galley, Glyph, LayoutJob, LayoutSection, Row, RowVisuals};
-use crate::{Color32, Mesh, Stroke, Vertex};
+use super::{Fonts, Galley, Glyph, LayoutJob, LayoutSection, Row, RowVisuals, WrapMode};
+use crate::{tessellator::Path, Color32, Mesh, Stroke, Vertex};
 use emath::*;
 
 /// Temporary storage before line-wrapping.
@@ -25,9 +25,15 @@ pub fn layout(fonts: &Fonts, job: Arc<LayoutJob>) -> Galley {
         layout_section(fonts, &job, section_index as u32, section, &mut paragraphs);
     }
 
-    let mut rows = rows_from_paragraphs(paragraphs, job.wrap_width);
+    let wrap_width = if job.wrap_mode == WrapMode::Extend {
+        f32::INFINITY
+    } else {
+        job.wrap_width
+    };
 
-    let justify = job.justify && job.wrap_width.is_finite();
+    let mut rows = rows_from_paragraphs(paragraphs, wrap_width, job.wrap_mode);
+
+    let justify = job.justify && wrap_width.is_finite();
 
     if justify || job.halign != Align::LEFT {
         let num_rows = rows.len();
@@ -61,30 +67,55 @@ fn layout_section(
         paragraph.empty_paragraph_height = font_height; // TODO: replace this hack with actually including `\n` in the glyphs?
     }
 
+    let text = &job.text[byte_range.clone()];
+
+    // Upper bound on the number of glyphs in the paragraph starting at `rest`,
+    // so we don't need to grow the buffer char by char.
+    // Only up to the next newline, since the buffer ends up in a `Row` of the `Galley`.
+    let paragraph_len = |rest: &str| match rest.find('\n').filter(|_| job.break_on_newline) {
+        Some(newline) => newline,
+        None => rest.len(),
+    };
+    paragraph.glyphs.reserve(paragraph_len(text));
+
     paragraph.cursor_x += leading_space;
 
     let mut last_glyph_id = None;
+    // Keep tab stops on the pixel grid, or a tab after a rounded-down `cursor_x`
+    // could find the same tab stop as the tab before it:
+    let tab_width = font.round_to_pixel(job.tab_size as f32 * font.glyph_width(' '));
 
-    for chr in job.text[byte_range.clone()].chars() {
+    for (byte_index, chr) in text.char_indices() {
         if job.break_on_newline && chr == '\n' {
             out_paragraphs.push(Paragraph::default());
             paragraph = out_paragraphs.last_mut().unwrap();
             paragraph.empty_paragraph_height = font_height; // TODO: replace this hack with actually including `\n` in the glyphs?
+            paragraph
+                .glyphs
+                .reserve(paragraph_len(&text[byte_index + 1..]));
         } else {
             let (font_impl, glyph_info) = font.glyph_info_and_font_impl(chr);
-            if let Some(last_glyph_id) = last_glyph_id {
+            if let Some(last_glyph_id) = last_glyph_id.filter(|_| job.kerning) {
                 paragraph.cursor_x += font_impl.pair_kerning(last_glyph_id, glyph_info.id)
             }
 
+            let advance_width = if chr == '\t' && tab_width > 0.0 {
+                let next_tab_stop = ((paragraph.cursor_x / tab_width).floor() + 1.0) * tab_width;
+                next_tab_stop - paragraph.cursor_x
+            } else {
+                glyph_info.advance_width
+            };
+
             paragraph.glyphs.push(Glyph {
                 chr,
-                pos: pos2(paragraph.cursor_x, f32::NAN),
-                size: vec2(glyph_info.advance_width, font_height),
+                // Kerning and leading space may have moved us off the pixel grid:
+                pos: pos2(font.round_to_pixel(paragraph.cursor_x), f32::NAN),
+                size: vec2(advance_width, font_height),
                 uv_rect: glyph_info.uv_rect,
                 section_index,
             });
 
-            paragraph.cursor_x += glyph_info.advance_width;
+            paragraph.cursor_x += advance_width;
             paragraph.cursor_x = font.round_to_pixel(paragraph.cursor_x);
             last_glyph_id = Some(glyph_info.id);
         }
@@ -96,10 +127,14 @@ fn rect_from_x_range(x_range: RangeInclusive<f32>) -> Rect {
     Rect::from_x_y_ranges(x_range, 0.0..=0.0)
 }
 
-fn rows_from_paragraphs(paragraphs: Vec<Paragraph>, wrap_width: f32) -> Vec<Row> {
+fn rows_from_paragraphs(
+    paragraphs: Vec<Paragraph>,
+    wrap_width: f32,
+    wrap_mode: WrapMode,
+) -> Vec<Row> {
     let num_paragraphs = paragraphs.len();
 
-    let mut rows = vec![];
+    let mut rows = Vec::with_capacity(num_paragraphs);
 
     for (i, paragraph) in paragraphs.into_iter().enumerate() {
         let is_last_paragraph = (i + 1) == num_paragraphs;
@@ -126,7 +161,7 @@ fn rows_from_paragraphs(paragraphs: Vec<Paragraph>, wrap_width: f32) -> Vec<Row>
                     ends_with_newline: !is_last_paragraph,
                 });
             } else {
-                line_break(&paragraph, wrap_width, &mut rows);
+                line_break(paragraph, wrap_width, wrap_mode, &mut rows);
                 rows.last_mut().unwrap().ends_with_newline = !is_last_paragraph;
             }
         }
@@ -135,7 +170,8 @@ fn rows_from_paragraphs(paragraphs: Vec<Paragraph>, wrap_width: f32) -> Vec<Row>
     rows
 }
 
-fn line_break(paragraph: &Paragraph, wrap_width: f32, out_rows: &mut Vec<Row>) {
+/// Takes ownership of the paragraph so that the last row can re-use its glyph buffer.
+fn line_break(paragraph: Paragraph, wrap_width: f32, wrap_mode: WrapMode, out_rows: &mut Vec<Row>) {
     // Keeps track of good places to insert row break if we exceed `wrap_width`.
     let mut row_break_candidates = RowBreakCandidates::default();
 
@@ -147,7 +183,16 @@ fn line_break(paragraph: &Paragraph, wrap_width: f32, out_rows: &mut Vec<Row>) {
         let potential_row_width = glyph.max_x() - row_start_x;
 
         if potential_row_width > wrap_width {
-            if first_row_indentation > 0.0 && !row_break_candidates.has_word_boundary() {
+            let last_kept_index = match wrap_mode {
+                WrapMode::Char => i.checked_sub(1).filter(|&index| index >= row_start_idx),
+                WrapMode::Word | WrapMode::Extend => row_break_candidates.get(),
+            };
+            let can_fit_on_first_row = match wrap_mode {
+                WrapMode::Char => last_kept_index.is_some(),
+                WrapMode::Word | WrapMode::Extend => row_break_candidates.has_word_boundary(),
+            };
+
+            if first_row_indentation > 0.0 && !can_fit_on_first_row {
                 // Allow the first row to be completely empty, because we know there will be more space on the next row:
                 // TODO: this records the height of this first row as zero, though that is probably fine since first_row_indentation usually comes with a first_row_min_height.
                 out_rows.push(Row {
@@ -158,7 +203,7 @@ fn line_break(paragraph: &Paragraph, wrap_width: f32, out_rows: &mut Vec<Row>) {
                 });
                 row_start_x += first_row_indentation;
                 first_row_indentation = 0.0;
-            } else if let Some(last_kept_index) = row_break_candidates.get() {
+            } else if let Some(last_kept_index) = last_kept_index {
                 let glyphs: Vec<Glyph> = paragraph.glyphs[row_start_idx..=last_kept_index]
                     .iter()
                     .copied()
@@ -190,14 +235,12 @@ fn line_break(paragraph: &Paragraph, wrap_width: f32, out_rows: &mut Vec<Row>) {
     }
 
     if row_start_idx < paragraph.glyphs.len() {
-        let glyphs: Vec<Glyph> = paragraph.glyphs[row_start_idx..]
-            .iter()
-            .copied()
-            .map(|mut glyph| {
-                glyph.pos.x -= row_start_x;
-                glyph
-            })
-            .collect();
+        // Re-use the allocation of the paragraph for the last row:
+        let mut glyphs = paragraph.glyphs;
+        glyphs.drain(..row_start_idx);
+        for glyph in &mut glyphs {
+            glyph.pos.x -= row_start_x;
+        }
 
         let paragraph_min_x = glyphs[0].pos.x;
         let paragraph_max_x = glyphs.last().unwrap().max_x();
@@ -307,7 +350,14 @@ fn galley_from_rows(fonts: &Fonts, job: Arc<LayoutJob>, mut rows: Vec<Row>) -> G
     let mut cursor_y = 0.0;
     let mut min_x: f32 = 0.0;
     let mut max_x: f32 = 0.0;
-    for row in &mut rows {
+    let num_rows = rows.len();
+    // A paragraph starts at each non-empty row after a `\n`:
+    let starts_paragraph: Vec<bool> = rows
+        .iter()
+        .map(|row| !row.glyphs.is_empty())
+        .skip(1)
+        .collect();
+    for (i, row) in rows.iter_mut().enumerate() {
         let mut row_height = first_row_min_height.max(row.rect.height());
         first_row_min_height = 0.0;
         for glyph in &row.glyphs {
@@ -328,6 +378,12 @@ fn galley_from_rows(fonts: &Fonts, job: Arc<LayoutJob>, mut rows: Vec<Row>) -> G
         min_x = min_x.min(row.rect.min.x);
         max_x = max_x.max(row.rect.max.x);
         cursor_y += row_height;
+        if i + 1 < num_rows {
+            cursor_y += (job.line_height_factor - 1.0) * row_height;
+            if row.ends_with_newline && starts_paragraph[i] {
+                cursor_y += job.paragraph_spacing;
+            }
+        }
         cursor_y = fonts.round_to_pixel(cursor_y);
     }
 
@@ -336,8 +392,11 @@ fn galley_from_rows(fonts: &Fonts, job: Arc<LayoutJob>, mut rows: Vec<Row>) -> G
     let mut num_vertices = 0;
     let mut num_indices = 0;
 
+    // Scratch space for underlines and strikethrough, shared by all rows:
+    let mut path = Path::default();
+
     for row in &mut rows {
-        row.visuals = tessellate_row(fonts, &job, &format_summary, row);
+        row.visuals = tessellate_row(fonts, &job, &format_summary, &mut path, row);
         num_vertices += row.visuals.mesh.vertices.len();
         num_indices += row.visuals.mesh.indices.len();
     }
@@ -374,6 +433,7 @@ fn tessellate_row(
     fonts: &Fonts,
     job: &LayoutJob,
     format_summary: &FormatSummary,
+    path: &mut Path,
     row: &mut Row,
 ) -> RowVisuals {
     if row.glyphs.is_empty() {
@@ -394,7 +454,7 @@ fn tessellate_row(
     let glyph_vertex_end = mesh.vertices.len();
 
     if format_summary.any_underline {
-        add_row_hline(fonts, row, &mut mesh, |glyph| {
+        add_row_hline(fonts, row, path, &mut mesh, |glyph| {
             let format = &job.sections[glyph.section_index as usize].format;
             let stroke = format.underline;
             let y = glyph.logical_rect().bottom();
@@ -403,7 +463,7 @@ fn tessellate_row(
     }
 
     if format_summary.any_strikethrough {
-        add_row_hline(fonts, row, &mut mesh, |glyph| {
+        add_row_hline(fonts, row, path, &mut mesh, |glyph| {
             let format = &job.sections[glyph.section_index as usize].format;
             let stroke = format.strikethrough;
             let y = glyph.logical_rect().center().y;
@@ -521,12 +581,13 @@ fn tessellate_glyphs(fonts: &Fonts, job: &LayoutJob, row: &Row, mesh: &mut Mesh)
 fn add_row_hline(
     fonts: &Fonts,
     row: &Row,
+    path: &mut Path,
     mesh: &mut Mesh,
     stroke_and_y: impl Fn(&Glyph) -> (Stroke, f32),
 ) {
     let mut end_line = |start: Option<(Stroke, Pos2)>, stop_x: f32| {
         if let Some((stroke, start)) = start {
-            add_hline(fonts, [start, pos2(stop_x, start.y)], stroke, mesh);
+            add_hline(fonts, [start, pos2(stop_x, start.y)], stroke, path, mesh);
         }
     };
 
@@ -555,11 +616,17 @@ fn add_row_hline(
     end_line(line_start.take(), last_right_x);
 }
 
-fn add_hline(fonts: &Fonts, [start, stop]: [Pos2; 2], stroke: Stroke, mesh: &mut Mesh) {
+fn add_hline(
+    fonts: &Fonts,
+    [start, stop]: [Pos2; 2],
+    stroke: Stroke,
+    path: &mut Path,
+    mesh: &mut Mesh,
+) {
     let antialiased = true;
 
     if antialiased {
-        let mut path = crate::tessellator::Path::default(); // TODO: reuse this to avoid re-allocations.
+        path.clear();
         path.add_line_segment([start, stop]);
         let options = crate::tessellator::TessellationOptions::from_pixels_per_point(
             fonts.pixels_per_point(),
@@ -639,3 +706,209 @@ fn is_chinese(c: char) -> bool {
         || ('\u{3400}' <= c && c <= '\u{4DBF}')
         || ('\u{2B740}' <= c && c <= '\u{2B81F}')
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        text::{TextFormat, TAB_SIZE},
+        TextStyle,
+    };
+
+    fn fonts() -> Fonts {
+        Fonts::from_definitions(1.0, Default::default())
+    }
+
+    /// Lay out `text` in a single `style` section of `job`, at one pixel per point.
+    fn layout_with(mut job: LayoutJob, text: &str, style: TextStyle) -> Galley {
+        job.append(text, 0.0, TextFormat::simple(style, Color32::WHITE));
+        layout(&fonts(), job.into())
+    }
+
+    fn row_texts(galley: &Galley) -> Vec<String> {
+        galley
+            .rows
+            .iter()
+            .map(|row| row.glyphs.iter().map(|g| g.chr).collect())
+            .collect()
+    }
+
+    #[test]
+    fn glyphs_are_snapped_to_pixels() {
+        let pixels_per_point = 1.5;
+        let fonts = Fonts::from_definitions(pixels_per_point, Default::default());
+        let mut job = LayoutJob::default();
+        job.append(
+            "AVAVA Wave To",
+            1.0 / 3.0,
+            TextFormat::simple(TextStyle::Body, Color32::WHITE),
+        );
+        let galley = layout(&fonts, job.into());
+        for glyph in &galley.rows[0].glyphs {
+            let x = glyph.pos.x * pixels_per_point;
+            assert!(
+                (x - x.round()).abs() < 1e-3,
+                "{:?} is not on a pixel",
+                glyph
+            );
+        }
+    }
+
+    #[test]
+    fn wrap_modes_with_long_token() {
+        let text = "ab https://example.com/a/very/long/url";
+        let wrapped = |wrap_mode: WrapMode| {
+            let job = LayoutJob {
+                wrap_width: 60.0,
+                wrap_mode,
+                ..Default::default()
+            };
+            row_texts(&layout_with(job, text, TextStyle::Body))
+        };
+
+        let word = wrapped(WrapMode::Word);
+        assert_eq!(word[0], "ab ", "Word should break at the space first");
+        assert!(word.len() > 2, "Word should fall back to breaking the url");
+
+        let chr = wrapped(WrapMode::Char);
+        assert!(chr[0].starts_with("ab h"), "Char should fill the first row");
+        assert_eq!(chr.concat(), text);
+
+        assert_eq!(wrapped(WrapMode::Extend).len(), 1);
+    }
+
+    #[test]
+    fn line_height_factor() {
+        let height = |text: &str, line_height_factor: f32| {
+            let job = LayoutJob {
+                line_height_factor,
+                ..Default::default()
+            };
+            layout_with(job, text, TextStyle::Body).size().y
+        };
+
+        let row_height = fonts().row_height(TextStyle::Body);
+        let gap = height("one\ntwo\nthree", 1.5) - height("one\ntwo\nthree", 1.0);
+        assert!(
+            (gap - row_height).abs() <= 1.0,
+            "two gaps of half a row each"
+        );
+        assert_eq!(height("one", 1.5), height("one", 1.0));
+    }
+
+    #[test]
+    fn paragraph_spacing() {
+        let height = |text: &str, paragraph_spacing: f32| {
+            let job = LayoutJob {
+                paragraph_spacing,
+                wrap_width: 50.0,
+                ..Default::default()
+            };
+            layout_with(job, text, TextStyle::Body).size().y
+        };
+
+        let text = "a paragraph that wraps onto several rows\nanother one that also wraps";
+        assert_eq!(height(text, 10.0) - height(text, 0.0), 10.0);
+        assert_eq!(height("one", 10.0), height("one", 0.0));
+        assert_eq!(height("one\n", 10.0), height("one\n", 0.0));
+        assert_eq!(height("one\n\ntwo", 10.0) - height("one\n\ntwo", 0.0), 10.0);
+    }
+
+    #[test]
+    fn without_kerning_glyphs_follow_the_advance_widths() {
+        let text = "AVATAR To";
+        let glyph_positions = |kerning: bool| -> Vec<f32> {
+            let job = LayoutJob {
+                kerning,
+                ..Default::default()
+            };
+            let galley = layout_with(job, text, TextStyle::Body);
+            galley.rows[0].glyphs.iter().map(|g| g.pos.x).collect()
+        };
+
+        let fonts = fonts();
+        let font = &fonts[TextStyle::Body];
+        let mut x = 0.0;
+        let mut expected = vec![];
+        for chr in text.chars() {
+            // The layout rounds to a pixel after each glyph:
+            expected.push(x);
+            x = font.round_to_pixel(x + font.glyph_width(chr));
+        }
+        assert_eq!(glyph_positions(false), expected);
+
+        // Kerning only ever tucks glyphs closer together (or leaves them be) in these pairs:
+        for (kerned, unkerned) in glyph_positions(true).iter().zip(&expected) {
+            assert!(kerned <= unkerned);
+        }
+    }
+
+    #[test]
+    fn missing_glyphs_are_replaced() {
+        let fonts = fonts();
+        let font = &fonts[TextStyle::Body];
+        let missing = '\u{7}'; // a control character, which no font has a glyph for
+        assert_eq!(font.glyph_width(missing), font.glyph_width('◻'));
+
+        let job = LayoutJob {
+            kerning: false,
+            ..Default::default()
+        };
+        let galley = layout_with(job, "a\u{7}b", TextStyle::Body);
+        let glyphs = &galley.rows[0].glyphs;
+        assert_eq!(glyphs.len(), 3);
+        assert_eq!(glyphs[1].size.x, font.glyph_width('◻'));
+        assert_eq!(
+            glyphs[2].pos.x,
+            font.round_to_pixel(font.glyph_width('a') + font.glyph_width('◻'))
+        );
+    }
+
+    #[test]
+    fn emojis_come_from_the_fallback_font() {
+        let galley = layout_with(LayoutJob::default(), "a🔍", TextStyle::Body);
+        let glyphs = &galley.rows[0].glyphs;
+        assert_eq!(glyphs.len(), 2);
+        assert!(glyphs[0].size.x > 0.0);
+        assert!(glyphs[1].size.x > 0.0);
+
+        let replacement = layout_with(LayoutJob::default(), "◻", TextStyle::Body);
+        assert_ne!(glyphs[1].uv_rect, replacement.rows[0].glyphs[0].uv_rect);
+    }
+
+    #[test]
+    fn rows_only_keep_room_for_their_own_glyphs() {
+        let text = "a row\n".repeat(100);
+        let galley = layout_with(LayoutJob::default(), &text, TextStyle::Body);
+        for row in &galley.rows {
+            assert!(row.glyphs.capacity() < 10, "{}", row.glyphs.capacity());
+        }
+    }
+
+    #[test]
+    fn tabs_align_to_tab_stops() {
+        let tab_stop_x = |text: &str, style: TextStyle| {
+            let galley = layout_with(LayoutJob::default(), text, style);
+            let row = &galley.rows[0];
+            row.glyphs.iter().find(|g| g.chr == 'x').unwrap().pos.x
+        };
+        let fonts = fonts();
+
+        let style = TextStyle::Monospace;
+        let tab_width = TAB_SIZE as f32 * fonts[style].glyph_width(' ');
+        assert_eq!(tab_stop_x("\tx", style), tab_width);
+        assert_eq!(tab_stop_x("a\tx", style), tab_width);
+        assert_eq!(tab_stop_x("abc\tx", style), tab_width);
+        assert_eq!(tab_stop_x("abcd\tx", style), 2.0 * tab_width);
+        assert_eq!(tab_stop_x("\t\tx", style), 2.0 * tab_width);
+
+        // The spaces of a proportional font are not a whole number of pixels wide:
+        let style = TextStyle::Body;
+        let tab_width =
+            fonts[style].round_to_pixel(TAB_SIZE as f32 * fonts[style].glyph_width(' '));
+        assert_eq!(tab_stop_x("\tx", style), tab_width);
+        assert_eq!(tab_stop_x("\t\tx", style), 2.0 * tab_width);
+        assert_eq!(tab_stop_x("\t\t\tx", style), 3.0 * tab_width);
+        assert_eq!(tab_stop_x("a\t\tx", style), 2.0 * tab_width);
+    }
+}
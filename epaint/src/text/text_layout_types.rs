@@ -25,6 +25,10 @@ pub struct LayoutJob {
     /// Note that `\n` always produces a new line.
     pub wrap_width: f32,
 
+    /// How to break rows that are wider than [`Self::wrap_width`].
+    /// Default: [`WrapMode::Word`].
+    pub wrap_mode: WrapMode,
+
     /// The first row must be at least this high.
     /// This is in case we lay out text that is the continuation
     /// of some earlier text (sharing the same row),
@@ -32,6 +36,25 @@ pub struct LayoutJob {
     /// In other cases, set this to `0.0`.
     pub first_row_min_height: f32,
 
+    /// Multiply the distance between rows with this, to add extra space (leading) between them.
+    /// Does not add any space after the last row, so single-line text is unaffected.
+    /// Default: `1.0`.
+    pub line_height_factor: f32,
+
+    /// Extra space to add between paragraphs, i.e. before text that follows a `\n`.
+    /// Blank lines (`\n\n`) don't add any more, and neither does a trailing `\n`.
+    /// Default: `0.0`.
+    pub paragraph_spacing: f32,
+
+    /// If `false`, glyphs are placed one advance width after another,
+    /// ignoring the kerning of the font. Useful to keep monospace text on a strict grid.
+    /// Default: `true`.
+    pub kerning: bool,
+
+    /// A `\t` moves the text on to the next tab stop, which are placed every `tab_size` space widths.
+    /// Default: [`super::TAB_SIZE`].
+    pub tab_size: usize,
+
     /// If `false`, all newlines characters will be ignored
     /// and show up as the replacement character.
     /// Default: `true`.
@@ -51,7 +74,12 @@ impl Default for LayoutJob {
             text: Default::default(),
             sections: Default::default(),
             wrap_width: f32::INFINITY,
+            wrap_mode: WrapMode::Word,
             first_row_min_height: 0.0,
+            line_height_factor: 1.0,
+            paragraph_spacing: 0.0,
+            kerning: true,
+            tab_size: super::TAB_SIZE,
             break_on_newline: true,
             halign: Align::LEFT,
             justify: false,
@@ -117,7 +145,12 @@ impl std::hash::Hash for LayoutJob {
             text,
             sections,
             wrap_width,
+            wrap_mode,
             first_row_min_height,
+            line_height_factor,
+            paragraph_spacing,
+            kerning,
+            tab_size,
             break_on_newline,
             halign,
             justify,
@@ -126,7 +159,12 @@ impl std::hash::Hash for LayoutJob {
         text.hash(state);
         sections.hash(state);
         crate::f32_hash(state, *wrap_width);
+        wrap_mode.hash(state);
         crate::f32_hash(state, *first_row_min_height);
+        crate::f32_hash(state, *line_height_factor);
+        crate::f32_hash(state, *paragraph_spacing);
+        kerning.hash(state);
+        tab_size.hash(state);
         break_on_newline.hash(state);
         halign.hash(state);
         justify.hash(state);
@@ -160,6 +198,20 @@ impl std::hash::Hash for LayoutSection {
 
 // ----------------------------------------------------------------------------
 
+/// How to break text that is wider than [`LayoutJob::wrap_width`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum WrapMode {
+    /// Break at spaces and other word boundaries.
+    /// A word that is too long to fit on a row by itself is broken wherever it must.
+    Word,
+    /// Break after the last character that fits, e.g. for URLs or hex dumps.
+    Char,
+    /// Never break rows, letting them extend beyond the wrap width.
+    Extend,
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Copy, Clone, Debug, Hash, PartialEq)]
 pub struct TextFormat {
     pub style: TextStyle,